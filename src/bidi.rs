@@ -0,0 +1,39 @@
+//! Unicode bidirectional formatting characters, to render mixed arabic and latin text in the
+//! right order in terminals, logs and HTML.
+//!
+//! ```rust
+//! use hijri_date::{DateFormat, FormatStyle, HijriDate, Locale};
+//! use hijri_date::bidi::{ALM, PDI, RLI};
+//!
+//! let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+//! let short = DateFormat::new(FormatStyle::Short, Locale::Arabic).bidi(true);
+//! assert_eq!(short.format(&hd), format!("{}1{}/9{}/1445{}", RLI, ALM, ALM, PDI));
+//!
+//! // `{:#}` isolates the arabic date to embed it in latin text
+//! assert_eq!(format!("{:#}", hd), format!("{}{}{}", RLI, hd, PDI));
+//! ```
+
+/// Left-to-right isolate
+pub const LRI: char = '\u{2066}';
+/// Right-to-left isolate
+pub const RLI: char = '\u{2067}';
+/// First strong isolate, direction taken from the first strong character
+pub const FSI: char = '\u{2068}';
+/// Pop directional isolate, ends `LRI`, `RLI` and `FSI`
+pub const PDI: char = '\u{2069}';
+/// Arabic letter mark
+pub const ALM: char = '\u{061C}';
+/// Left-to-right mark
+pub const LRM: char = '\u{200E}';
+/// Right-to-left mark
+pub const RLM: char = '\u{200F}';
+
+/// Wraps `s` in a right-to-left isolate
+pub fn isolate_rtl(s: &str) -> String {
+    format!("{}{}{}", RLI, s, PDI)
+}
+
+/// Wraps `s` in a left-to-right isolate
+pub fn isolate_ltr(s: &str) -> String {
+    format!("{}{}{}", LRI, s, PDI)
+}
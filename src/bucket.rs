@@ -0,0 +1,148 @@
+//! Grouping of dated rows by hijri year, month or week, for reports and dashboards.
+//!
+//! Rows are either dates (`NaiveDate`, `NaiveDateTime`, `DateTime`) or `(date, value)` pairs.
+//! The buckets are returned in chronological order, with an empty bucket for every period
+//! without rows between the first and the last one.
+//!
+//! ```rust
+//! # #[cfg(feature = "chrono")]
+//! # {
+//! use chrono::NaiveDate;
+//! use hijri_date::bucket;
+//! use hijri_date::HijriMonth::{DhulHijjah, Ramadan, Shawwal};
+//! use hijri_date::HijriYearMonth;
+//!
+//! let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+//! let sales = vec![
+//!     (date(2024, 3, 11), 120), // 1 Ramadan 1445
+//!     (date(2024, 4, 9), 80),   // 30 Ramadan 1445
+//!     (date(2024, 6, 8), 50),   // 2 Dhu al-Hijjah 1445
+//! ];
+//!
+//! let months = bucket::by_month(sales).unwrap();
+//! assert_eq!(months.len(), 4);
+//! assert_eq!(months[0], (HijriYearMonth::new(1445, Ramadan), vec![120, 80]));
+//! assert_eq!(months[1], (HijriYearMonth::new(1445, Shawwal), vec![]));
+//! assert_eq!(months[3], (HijriYearMonth::new(1445, DhulHijjah), vec![50]));
+//! # }
+//! ```
+
+use crate::umalqura::mcjdn_to_hijri;
+use crate::{DayNumber, HijriDate, HijriMonth, HijriYearMonth, WeekNumbering};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone};
+use std::collections::BTreeMap;
+
+/// Buckets in chronological order, each with its key and the values of its rows
+pub type Buckets<K, V> = Vec<(K, Vec<V>)>;
+
+// value of the rows of an iterator
+type Value<I> = <<I as IntoIterator>::Item as Dated>::Value;
+
+/// Row that can be bucketed, a date or a `(date, value)` pair.
+pub trait Dated {
+    type Value;
+
+    /// Day of the row and the value to put in its bucket
+    fn into_parts(self) -> (DayNumber, Self::Value);
+}
+
+#[cfg(feature = "chrono")]
+impl Dated for NaiveDate {
+    type Value = NaiveDate;
+
+    fn into_parts(self) -> (DayNumber, NaiveDate) {
+        (self.into(), self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Dated for NaiveDateTime {
+    type Value = NaiveDateTime;
+
+    fn into_parts(self) -> (DayNumber, NaiveDateTime) {
+        (self.into(), self)
+    }
+}
+
+/// Bucketed by the day in the time zone of the date and time
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> Dated for DateTime<Tz> {
+    type Value = DateTime<Tz>;
+
+    fn into_parts(self) -> (DayNumber, DateTime<Tz>) {
+        (self.date_naive().into(), self)
+    }
+}
+
+impl<D: Into<DayNumber>, V> Dated for (D, V) {
+    type Value = V;
+
+    fn into_parts(self) -> (DayNumber, V) {
+        (self.0.into(), self.1)
+    }
+}
+
+/// Groups the rows by hijri year
+pub fn by_year<I>(items: I) -> Result<Buckets<i32, Value<I>>, String>
+where
+    I: IntoIterator,
+    I::Item: Dated,
+{
+    group(items, |mcjdn| mcjdn_to_hijri(mcjdn).0 as i32)
+}
+
+/// Groups the rows by hijri month
+pub fn by_month<I>(items: I) -> Result<Buckets<HijriYearMonth, Value<I>>, String>
+where
+    I: IntoIterator,
+    I::Item: Dated,
+{
+    group(items, |mcjdn| {
+        let (year, month, ..) = mcjdn_to_hijri(mcjdn);
+        // the table only returns valid months
+        HijriYearMonth::new(year as i32, HijriMonth::from_number(month).unwrap())
+    })
+}
+
+/// Groups the rows by (week-based year, week), see [`WeekNumbering`]
+pub fn by_week<I>(
+    items: I,
+    numbering: &WeekNumbering,
+) -> Result<Buckets<(i32, usize), Value<I>>, String>
+where
+    I: IntoIterator,
+    I::Item: Dated,
+{
+    group(items, |mcjdn| numbering.week_of_mcjdn(mcjdn))
+}
+
+// keys are computed from the table, no HijriDate is built per row
+fn group<I, K>(items: I, key: impl Fn(usize) -> K) -> Result<Buckets<K, Value<I>>, String>
+where
+    I: IntoIterator,
+    I::Item: Dated,
+    K: Ord,
+{
+    let mut buckets = BTreeMap::new();
+    let mut range: Option<(usize, usize)> = None;
+    for item in items {
+        let (day_number, value) = item.into_parts();
+        let mcjdn = HijriDate::checked_mcjdn(day_number)?;
+        range = Some(match range {
+            Some((first, last)) => (first.min(mcjdn), last.max(mcjdn)),
+            None => (mcjdn, mcjdn),
+        });
+        buckets
+            .entry(key(mcjdn))
+            .or_insert_with(Vec::new)
+            .push(value);
+    }
+
+    if let Some((first, last)) = range {
+        for mcjdn in first..=last {
+            buckets.entry(key(mcjdn)).or_insert_with(Vec::new);
+        }
+    }
+    Ok(buckets.into_iter().collect())
+}
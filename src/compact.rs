@@ -0,0 +1,322 @@
+use crate::month::GREGORIAN_MONTHS;
+use crate::umalqura::*;
+use crate::{DateError, DayNumber, HijriMonth, HijriWeekday, Locale};
+use core::fmt;
+use core::ops::Sub;
+
+/// Hijri date of the table without allocation, available without the `std` feature.
+///
+/// Conversion, validation and arithmetic are `const` where possible. Unlike
+/// [`HijriDate`](crate::HijriDate), it is `Copy` and fits in 8 bytes.
+///
+/// ```rust
+/// use hijri_date::{CompactHijriDate, DateError};
+///
+/// const RAMADAN: CompactHijriDate = match CompactHijriDate::from_hijri(1445, 9, 1) {
+///     Ok(date) => date,
+///     Err(_) => panic!("invalid hijri date"),
+/// };
+/// assert_eq!(RAMADAN.to_gregorian(), (2024, 3, 11));
+/// assert_eq!(CompactHijriDate::from_gr(2024, 3, 11), Ok(RAMADAN));
+/// assert_eq!(CompactHijriDate::from_hijri(1445, 9, 31), Err(DateError::InvalidDay(31)));
+///
+/// let eid = RAMADAN.checked_add_months(1).unwrap();
+/// assert_eq!(eid - RAMADAN, 30);
+///
+/// let mut s = String::new();
+/// eid.write_format(&mut s, "%d %M %Y = %gd %gM %gY").unwrap();
+/// assert_eq!(s, "1 شوال 1445 = 10 April 2024");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompactHijriDate {
+    // first so that the derived ordering is chronological
+    mcjdn: u32,
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl CompactHijriDate {
+    /// First day handled by the table, 1356-01-01 (1937-03-14)
+    pub const MIN: CompactHijriDate = CompactHijriDate::from_mcjdn(MIN_MCJDN);
+
+    /// Last day handled by the table, 1500-12-30 (2077-11-16)
+    pub const MAX: CompactHijriDate = CompactHijriDate::from_mcjdn(MAX_MCJDN);
+
+    /// get data from hijri date
+    pub const fn from_hijri(year: i32, month: usize, day: usize) -> Result<Self, DateError> {
+        if month < 1 || month > 12 {
+            return Err(DateError::InvalidMonth(month));
+        }
+        if day < 1 || day > 30 {
+            return Err(DateError::InvalidDay(day));
+        }
+        if year < 0 {
+            return Err(DateError::OutOfRange);
+        }
+        match month_index(year as usize, month) {
+            Some(index) if day <= month_len(index) => {
+                Ok(Self::from_mcjdn(hijri_to_mcjdn(index, day)))
+            }
+            Some(_) => Err(DateError::InvalidDay(day)),
+            None => Err(DateError::OutOfRange),
+        }
+    }
+
+    /// get data from gregorian date.
+    pub const fn from_gr(year_gr: i32, month_gr: usize, day_gr: usize) -> Result<Self, DateError> {
+        if month_gr > 12 || day_gr > 31 {
+            return Err(DateError::InvalidGregorianDate);
+        }
+        match DayNumber::from_gregorian(year_gr, month_gr as u32, day_gr as u32) {
+            Some(day_number) => Self::from_day_number(day_number),
+            None => Err(DateError::InvalidGregorianDate),
+        }
+    }
+
+    /// get data from a day number.
+    pub const fn from_day_number(day_number: DayNumber) -> Result<Self, DateError> {
+        let mcjdn = day_number.jdn() - MCJDN_EPOCH;
+        if mcjdn < MIN_MCJDN as i64 || mcjdn > MAX_MCJDN as i64 {
+            return Err(DateError::OutOfRange);
+        }
+        Ok(Self::from_mcjdn(mcjdn as usize))
+    }
+
+    /// get data from a Julian Day Number.
+    pub const fn from_jdn(jdn: i64) -> Result<Self, DateError> {
+        Self::from_day_number(DayNumber::from_jdn(jdn))
+    }
+
+    pub const fn year(self) -> i32 {
+        self.year as i32
+    }
+
+    pub const fn month(self) -> usize {
+        self.month as usize
+    }
+
+    pub const fn day(self) -> usize {
+        self.day as usize
+    }
+
+    /// Number of days in the month, 29 or 30
+    pub const fn month_len(self) -> usize {
+        // the month of a date is always in the table
+        match month_index(self.year as usize, self.month as usize) {
+            Some(index) => month_len(index),
+            None => 0,
+        }
+    }
+
+    pub fn month_enum(self) -> HijriMonth {
+        // month is always valid
+        HijriMonth::from_number(self.month()).unwrap()
+    }
+
+    pub fn weekday(self) -> HijriWeekday {
+        self.day_number().weekday()
+    }
+
+    pub const fn day_number(self) -> DayNumber {
+        DayNumber::from_jdn(self.to_jdn())
+    }
+
+    pub const fn to_jdn(self) -> i64 {
+        self.mcjdn as i64 + MCJDN_EPOCH
+    }
+
+    /// Gregorian (year, month, day)
+    pub const fn to_gregorian(self) -> (i32, usize, usize) {
+        let (year, month, day) = self.day_number().gregorian();
+        (year, month as usize, day as usize)
+    }
+
+    /// Date `days` later, `None` out of the table
+    pub const fn checked_add_days(self, days: i64) -> Option<Self> {
+        let jdn = match self.to_jdn().checked_add(days) {
+            Some(jdn) => jdn,
+            None => return None,
+        };
+        match Self::from_jdn(jdn) {
+            Ok(date) => Some(date),
+            Err(_) => None,
+        }
+    }
+
+    /// Date `days` earlier, `None` out of the table
+    pub const fn checked_sub_days(self, days: i64) -> Option<Self> {
+        match days.checked_neg() {
+            Some(days) => self.checked_add_days(days),
+            None => None,
+        }
+    }
+
+    /// Same day `months` later (earlier if negative), clamped to the length of the month.
+    /// `None` out of the table.
+    pub const fn checked_add_months(self, months: i32) -> Option<Self> {
+        let total = self.year as i64 * 12 + self.month as i64 - 1 + months as i64;
+        if total < 0 {
+            return None;
+        }
+        let (year, month) = ((total / 12) as usize, (total % 12) as usize + 1);
+        let index = match month_index(year, month) {
+            Some(index) => index,
+            None => return None,
+        };
+        let day = if (self.day as usize) < month_len(index) {
+            self.day as usize
+        } else {
+            month_len(index)
+        };
+        Some(Self::from_mcjdn(hijri_to_mcjdn(index, day)))
+    }
+
+    /// Writes the date defined by the given formatter without allocating.
+    ///
+    /// Handles `%Y`, `%m`, `%d`, `%D`, `%M` and `%l` for the hijri date and `%gY`, `%gm`,
+    /// `%gd`, `%gD` and `%gM` for the gregorian one, the names being the ones of
+    /// [`HijriDate::format`](crate::HijriDate::format). Other characters are written as is.
+    pub fn write_format<W: fmt::Write>(self, w: &mut W, f: &str) -> fmt::Result {
+        let (year_gr, month_gr, day_gr) = self.to_gregorian();
+        let mut chars = f.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                w.write_char(c)?;
+                continue;
+            }
+            let rest = chars.as_str();
+            let (field, len) = match rest.as_bytes() {
+                [b'g', b'Y', ..] => (Field::Number(year_gr as i64), 2),
+                [b'g', b'm', ..] => (Field::Number(month_gr as i64), 2),
+                [b'g', b'd', ..] => (Field::Number(day_gr as i64), 2),
+                [b'g', b'D', ..] => (Field::Name(self.weekday().name(Locale::English)), 2),
+                [b'g', b'M', ..] => (Field::Name(GREGORIAN_MONTHS[month_gr - 1]), 2),
+                [b'Y', ..] => (Field::Number(self.year as i64), 1),
+                [b'm', ..] => (Field::Number(self.month as i64), 1),
+                [b'd', ..] => (Field::Number(self.day as i64), 1),
+                [b'D', ..] => (Field::Name(self.weekday().name(Locale::Arabic)), 1),
+                [b'M', ..] => (Field::Name(self.month_enum().name(Locale::Arabic)), 1),
+                [b'l', ..] => (Field::Number(self.month_len() as i64), 1),
+                _ => {
+                    w.write_char(c)?;
+                    continue;
+                }
+            };
+            match field {
+                Field::Number(n) => write!(w, "{}", n)?,
+                Field::Name(name) => w.write_str(name)?,
+            }
+            chars = rest[len..].chars();
+        }
+        Ok(())
+    }
+
+    const fn from_mcjdn(mcjdn: usize) -> Self {
+        let (year, month, day, _) = mcjdn_to_hijri(mcjdn);
+        CompactHijriDate {
+            mcjdn: mcjdn as u32,
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+enum Field {
+    Number(i64),
+    Name(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl From<CompactHijriDate> for crate::HijriDate {
+    fn from(date: CompactHijriDate) -> Self {
+        crate::HijriDate::from_mcjdn(date.mcjdn as usize)
+    }
+}
+
+/// Fails for extended dates out of the table
+#[cfg(feature = "std")]
+impl core::convert::TryFrom<&crate::HijriDate> for CompactHijriDate {
+    type Error = DateError;
+
+    fn try_from(date: &crate::HijriDate) -> Result<Self, DateError> {
+        CompactHijriDate::from_day_number(date.day_number())
+    }
+}
+
+#[cfg(feature = "time")]
+impl core::convert::TryFrom<time::Date> for CompactHijriDate {
+    type Error = DateError;
+
+    fn try_from(date: time::Date) -> Result<Self, DateError> {
+        CompactHijriDate::from_day_number(date.into())
+    }
+}
+
+/// Every date of the table is in the range of `time`
+#[cfg(feature = "time")]
+impl From<CompactHijriDate> for time::Date {
+    fn from(date: CompactHijriDate) -> Self {
+        // shouldn't fail
+        time::Date::from_julian_day(date.to_jdn() as i32).unwrap()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl core::convert::TryFrom<jiff::civil::Date> for CompactHijriDate {
+    type Error = DateError;
+
+    fn try_from(date: jiff::civil::Date) -> Result<Self, DateError> {
+        CompactHijriDate::from_day_number(date.into())
+    }
+}
+
+/// Every date of the table is in the range of `jiff`
+#[cfg(feature = "jiff")]
+impl From<CompactHijriDate> for jiff::civil::Date {
+    fn from(date: CompactHijriDate) -> Self {
+        let (year, month, day) = date.to_gregorian();
+        jiff::civil::date(year as i16, month as i8, day as i8)
+    }
+}
+
+/// Hijri date of the day of a date of any ICU4X calendar
+#[cfg(feature = "icu_calendar")]
+impl<A: icu_calendar::AsCalendar> core::convert::TryFrom<&icu_calendar::Date<A>>
+    for CompactHijriDate
+{
+    type Error = DateError;
+
+    fn try_from(date: &icu_calendar::Date<A>) -> Result<Self, DateError> {
+        CompactHijriDate::from_day_number(date.into())
+    }
+}
+
+/// Same day in the ICU4X Umm al-Qura calendar
+#[cfg(feature = "icu_calendar")]
+impl From<CompactHijriDate> for icu_calendar::Date<icu_calendar::islamic::IslamicUmmAlQura> {
+    fn from(date: CompactHijriDate) -> Self {
+        let (year, month, day) = date.to_gregorian();
+        // shouldn't fail
+        icu_calendar::Date::try_new_iso_date(year, month as u8, day as u8)
+            .unwrap()
+            .to_calendar(icu_calendar::islamic::IslamicUmmAlQura::new())
+    }
+}
+
+/// Number of days between two dates
+impl Sub for CompactHijriDate {
+    type Output = i64;
+
+    fn sub(self, other: CompactHijriDate) -> i64 {
+        self.mcjdn as i64 - other.mcjdn as i64
+    }
+}
+
+/// `year-month-day`, example `1445-09-01`
+impl fmt::Display for CompactHijriDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
@@ -0,0 +1,375 @@
+use crate::HijriWeekday;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone};
+#[cfg(any(
+    feature = "time",
+    feature = "jiff",
+    feature = "icu_calendar",
+    all(feature = "std", feature = "chrono")
+))]
+use core::convert::TryFrom;
+use core::ops::{Add, Sub};
+
+// julian day number of 1970-01-01
+const UNIX_EPOCH_JDN: i64 = 2_440_588;
+// julian day number of 1858-11-17, modified julian day 0
+const MJD_EPOCH_JDN: i64 = 2_400_001;
+// julian day number of 0000-12-31, rata die 0
+const RATA_DIE_EPOCH_JDN: i64 = 1_721_425;
+// julian day number of 0000-03-01 in the julian calendar
+const JULIAN_MARCH_0_JDN: i64 = 1_721_118;
+
+// day numbers whose gregorian and julian years fit in an i32
+const MIN_GREGORIAN_JDN: i64 = unwrap_jdn(DayNumber::from_gregorian(i32::MIN, 1, 1));
+const MAX_GREGORIAN_JDN: i64 = unwrap_jdn(DayNumber::from_gregorian(i32::MAX, 12, 31));
+const MIN_JULIAN_JDN: i64 = unwrap_jdn(DayNumber::from_julian(i32::MIN, 1, 1));
+const MAX_JULIAN_JDN: i64 = unwrap_jdn(DayNumber::from_julian(i32::MAX, 12, 31));
+
+/// Count of days, used as a pivot between the hijri and gregorian calendars.
+///
+/// It is stored as a Chronological Julian Day Number (JDN), the number of days since
+/// -4713-11-24 (proleptic gregorian) with the day starting at midnight.
+///
+/// ```text
+///     Julian Day Number           jdn
+///     Modified Julian Day         jdn - 2400001   (0 = 1858-11-17)
+///     Rata Die                    jdn - 1721425   (1 = 0001-01-01)
+/// ```
+///
+/// ```rust
+/// use hijri_date::{DayNumber, HijriDate};
+///
+/// let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+/// assert_eq!(hd.to_jdn(), 2_460_381);
+/// assert_eq!(hd.day_number(), DayNumber::from_gregorian(2024, 3, 11).unwrap());
+/// assert_eq!(HijriDate::from_mjd(hd.to_mjd()), Ok(hd));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayNumber(i64);
+
+impl DayNumber {
+    pub const fn from_jdn(jdn: i64) -> DayNumber {
+        DayNumber(jdn)
+    }
+
+    pub const fn jdn(self) -> i64 {
+        self.0
+    }
+
+    pub const fn from_mjd(mjd: i64) -> DayNumber {
+        DayNumber(mjd + MJD_EPOCH_JDN)
+    }
+
+    pub const fn mjd(self) -> i64 {
+        self.0 - MJD_EPOCH_JDN
+    }
+
+    pub const fn from_rata_die(rd: i64) -> DayNumber {
+        DayNumber(rd + RATA_DIE_EPOCH_JDN)
+    }
+
+    pub const fn rata_die(self) -> i64 {
+        self.0 - RATA_DIE_EPOCH_JDN
+    }
+
+    /// Day number of a proleptic gregorian date, year 0 being 1 BCE
+    pub const fn from_gregorian(year: i32, month: u32, day: u32) -> Option<DayNumber> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        //source from: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let (year, month, day) = (year as i64, month as i64, day as i64);
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        Some(DayNumber(era * 146_097 + doe - 719_468 + UNIX_EPOCH_JDN))
+    }
+
+    /// Proleptic gregorian (year, month, day) of the day number, year 0 being 1 BCE.
+    ///
+    /// Returns `None` when the year doesn't fit in an `i32`.
+    pub const fn to_gregorian(self) -> Option<(i32, u32, u32)> {
+        if self.0 < MIN_GREGORIAN_JDN || self.0 > MAX_GREGORIAN_JDN {
+            return None;
+        }
+        Some(self.gregorian())
+    }
+
+    // the caller must make sure the day number is in the range of to_gregorian
+    pub(crate) const fn gregorian(self) -> (i32, u32, u32) {
+        //source from: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = self.0 - UNIX_EPOCH_JDN + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        (year as i32, month as u32, day as u32)
+    }
+
+    /// Day number of a julian calendar date, year 0 being 1 BCE
+    pub const fn from_julian(year: i32, month: u32, day: u32) -> Option<DayNumber> {
+        if month < 1 || month > 12 || day < 1 || day > julian_days_in_month(year, month) {
+            return None;
+        }
+
+        // same as from_gregorian with eras of 4 years
+        let (year, month, day) = (year as i64, month as i64, day as i64);
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = y.div_euclid(4);
+        let yoe = y - era * 4;
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + day - 1;
+        let doe = yoe * 365 + doy;
+
+        Some(DayNumber(era * 1461 + doe + JULIAN_MARCH_0_JDN))
+    }
+
+    /// Julian calendar (year, month, day) of the day number, year 0 being 1 BCE.
+    ///
+    /// Returns `None` when the year doesn't fit in an `i32`.
+    pub const fn to_julian(self) -> Option<(i32, u32, u32)> {
+        if self.0 < MIN_JULIAN_JDN || self.0 > MAX_JULIAN_JDN {
+            return None;
+        }
+        Some(self.julian())
+    }
+
+    // the caller must make sure the day number is in the range of to_julian
+    pub(crate) const fn julian(self) -> (i32, u32, u32) {
+        let z = self.0 - JULIAN_MARCH_0_JDN;
+        let era = z.div_euclid(1461);
+        let doe = z - era * 1461;
+        let yoe = (doe - doe / 1460) / 365;
+        let doy = doe - 365 * yoe;
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 4 + if month <= 2 { 1 } else { 0 };
+
+        (year as i32, month as u32, day as u32)
+    }
+
+    pub fn weekday(self) -> HijriWeekday {
+        // jdn 0 is a monday
+        HijriWeekday::from_number((self.0 + 1).rem_euclid(7) as usize + 1).unwrap()
+    }
+
+    /// Current day in UTC
+    #[cfg(feature = "std")]
+    pub fn today() -> DayNumber {
+        DayNumber(UNIX_EPOCH_JDN + unix_seconds().div_euclid(86_400))
+    }
+}
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+fn unix_seconds() -> i64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(before) => -(before.duration().as_secs() as i64) - 1,
+    }
+}
+
+// the system clock isn't available in the browser
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+fn unix_seconds() -> i64 {
+    (date_now() / 1000.0).floor() as i64
+}
+
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = Date, js_name = now)]
+    fn date_now() -> f64;
+}
+
+const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+const fn julian_days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 => 29,
+        _ => days_in_month(1, month),
+    }
+}
+
+// Option::unwrap isn't const on the MSRV
+const fn unwrap_jdn(day_number: Option<DayNumber>) -> i64 {
+    match day_number {
+        Some(day_number) => day_number.0,
+        None => panic!("invalid date"),
+    }
+}
+
+impl Add<i64> for DayNumber {
+    type Output = DayNumber;
+
+    fn add(self, days: i64) -> DayNumber {
+        DayNumber(self.0 + days)
+    }
+}
+
+impl Sub<i64> for DayNumber {
+    type Output = DayNumber;
+
+    fn sub(self, days: i64) -> DayNumber {
+        DayNumber(self.0 - days)
+    }
+}
+
+impl Sub<DayNumber> for DayNumber {
+    type Output = i64;
+
+    fn sub(self, other: DayNumber) -> i64 {
+        self.0 - other.0
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for DayNumber {
+    fn from(date: NaiveDate) -> Self {
+        DayNumber::from_rata_die(date.num_days_from_ce() as i64)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDateTime> for DayNumber {
+    fn from(datetime: NaiveDateTime) -> Self {
+        datetime.date().into()
+    }
+}
+
+/// Day of the date and time in its own time zone
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> From<DateTime<Tz>> for DayNumber {
+    fn from(datetime: DateTime<Tz>) -> Self {
+        datetime.date_naive().into()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "chrono"))]
+impl TryFrom<DayNumber> for NaiveDate {
+    type Error = String;
+
+    fn try_from(day_number: DayNumber) -> Result<Self, Self::Error> {
+        i32::try_from(day_number.rata_die())
+            .ok()
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .ok_or(format!(
+                "julian day number {} is out of the chrono range",
+                day_number.jdn()
+            ))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for DayNumber {
+    fn from(date: time::Date) -> Self {
+        DayNumber(date.to_julian_day() as i64)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for DayNumber {
+    fn from(datetime: time::PrimitiveDateTime) -> Self {
+        datetime.date().into()
+    }
+}
+
+/// Day of the date and time at its own offset
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for DayNumber {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        datetime.date().into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<DayNumber> for time::Date {
+    type Error = time::error::ComponentRange;
+
+    fn try_from(day_number: DayNumber) -> Result<Self, Self::Error> {
+        let jdn = day_number.0.clamp(i32::MIN as i64, i32::MAX as i64);
+        time::Date::from_julian_day(jdn as i32)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<jiff::civil::Date> for DayNumber {
+    fn from(date: jiff::civil::Date) -> Self {
+        // every jiff date is a valid gregorian date
+        DayNumber::from_gregorian(date.year() as i32, date.month() as u32, date.day() as u32)
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<jiff::civil::DateTime> for DayNumber {
+    fn from(datetime: jiff::civil::DateTime) -> Self {
+        datetime.date().into()
+    }
+}
+
+/// Day of the zoned datetime in its own time zone
+#[cfg(feature = "jiff")]
+impl From<&jiff::Zoned> for DayNumber {
+    fn from(zoned: &jiff::Zoned) -> Self {
+        zoned.date().into()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<DayNumber> for jiff::civil::Date {
+    type Error = jiff::Error;
+
+    fn try_from(day_number: DayNumber) -> Result<Self, Self::Error> {
+        // out of the i16 range years are rejected like the other invalid ones
+        let (year, month, day) = day_number.to_gregorian().unwrap_or((i32::MAX, 1, 1));
+        let year = year.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        jiff::civil::Date::new(year, month as i8, day as i8)
+    }
+}
+
+/// Day of a date of any ICU4X calendar
+#[cfg(feature = "icu_calendar")]
+impl<A: icu_calendar::AsCalendar> From<&icu_calendar::Date<A>> for DayNumber {
+    fn from(date: &icu_calendar::Date<A>) -> Self {
+        let iso = date.to_iso();
+        let (year, month, day) = (iso.year().number, iso.month().ordinal, iso.day_of_month().0);
+        // every iso date is a valid gregorian date
+        DayNumber::from_gregorian(year, month, day).unwrap()
+    }
+}
+
+#[cfg(feature = "icu_calendar")]
+impl TryFrom<DayNumber> for icu_calendar::Date<icu_calendar::Iso> {
+    type Error = icu_calendar::CalendarError;
+
+    fn try_from(day_number: DayNumber) -> Result<Self, Self::Error> {
+        let (year, month, day) = day_number
+            .to_gregorian()
+            .ok_or(icu_calendar::CalendarError::OutOfRange)?;
+        icu_calendar::Date::try_new_iso_date(year, month as u8, day as u8)
+    }
+}
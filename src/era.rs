@@ -0,0 +1,119 @@
+use crate::month::normalize;
+use crate::{HijriDate, Locale};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// Era of a hijri or gregorian year.
+///
+/// Years are stored with astronomical numbering, year 0 being 1 BH (or 1 BCE) and year -1
+/// being 2 BH (or 2 BCE).
+///
+/// ```rust
+/// use hijri_date::{Era, HijriDate};
+///
+/// let hd = HijriDate::from_hijri_extended(-4, 9, 1).unwrap();
+/// assert_eq!((hd.era(), hd.year_of_era()), (Era::BH, 5));
+/// assert_eq!(Era::BH.astronomical_year(5), Some(-4));
+/// assert_eq!("5-9-1 BH".parse(), Ok(hd));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Era {
+    /// Anno Hegirae, after the Hijra
+    AH,
+    /// Before the Hijra
+    BH,
+    /// Common Era
+    CE,
+    /// Before the Common Era
+    BCE,
+}
+
+impl Era {
+    /// Era and year of era of an astronomical hijri year
+    pub fn hijri(year: i32) -> (Era, u32) {
+        if year > 0 {
+            (Era::AH, year as u32)
+        } else {
+            (Era::BH, (1 - year as i64) as u32)
+        }
+    }
+
+    /// Era and year of era of an astronomical gregorian year
+    pub fn gregorian(year: i32) -> (Era, u32) {
+        if year > 0 {
+            (Era::CE, year as u32)
+        } else {
+            (Era::BCE, (1 - year as i64) as u32)
+        }
+    }
+
+    /// Astronomical year of the given year of this era, `None` if it overflows an `i32`
+    pub fn astronomical_year(self, year_of_era: u32) -> Option<i32> {
+        let year = i32::try_from(year_of_era).ok()?;
+        match self {
+            Era::AH | Era::CE => Some(year),
+            Era::BH | Era::BCE => 1i32.checked_sub(year),
+        }
+    }
+
+    pub fn is_hijri(self) -> bool {
+        self == Era::AH || self == Era::BH
+    }
+
+    /// Whether years of this era count backward
+    pub fn is_before_epoch(self) -> bool {
+        self == Era::BH || self == Era::BCE
+    }
+
+    /// Abbreviation of the era in the given locale
+    pub fn suffix(self, locale: Locale) -> &'static str {
+        match (self, locale) {
+            (Era::AH, Locale::English) => "AH",
+            (Era::BH, Locale::English) => "BH",
+            (Era::CE, Locale::English) => "CE",
+            (Era::BCE, Locale::English) => "BCE",
+            (Era::AH, Locale::Arabic) => "هـ",
+            (Era::BH, Locale::Arabic) => "ق.هـ",
+            (Era::CE, Locale::Arabic) => "م",
+            (Era::BCE, Locale::Arabic) => "ق.م",
+        }
+    }
+}
+
+/// Parses the english or arabic abbreviation of the era, example `AH`, `B.H.`, `هـ`, `ق.م`
+impl FromStr for Era {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key: String = normalize(s).chars().filter(|&c| c != 'ـ').collect();
+        match key.as_str() {
+            "ah" | "ه" => Ok(Era::AH),
+            "bh" | "قه" => Ok(Era::BH),
+            "ce" | "ad" | "م" => Ok(Era::CE),
+            "bce" | "bc" | "قم" => Ok(Era::BCE),
+            _ => Err(format!("unknown era: {}", s.trim())),
+        }
+    }
+}
+
+impl HijriDate {
+    /// Hijri era, AH or BH
+    pub fn era(&self) -> Era {
+        Era::hijri(self.year()).0
+    }
+
+    /// Hijri year counted in its era, 1 BH being the year before 1 AH
+    pub fn year_of_era(&self) -> u32 {
+        Era::hijri(self.year()).1
+    }
+
+    /// Gregorian era, CE or BCE
+    pub fn era_gr(&self) -> Era {
+        Era::gregorian(self.year_gr()).0
+    }
+
+    /// Gregorian year counted in its era, 1 BCE being the year before 1 CE
+    pub fn year_of_era_gr(&self) -> u32 {
+        Era::gregorian(self.year_gr()).1
+    }
+}
@@ -0,0 +1,31 @@
+use core::fmt;
+
+/// Error of the allocation free API, see [`CompactHijriDate`](crate::CompactHijriDate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateError {
+    /// Month not between 1 and 12
+    InvalidMonth(usize),
+    /// Day not in the month
+    InvalidDay(usize),
+    /// Gregorian date that doesn't exist
+    InvalidGregorianDate,
+    /// Valid date out of the range handled by the table, 1356-01-01 to 1500-12-30
+    OutOfRange,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateError::InvalidMonth(month) => write!(f, "enter a valid month, Err m = {}", month),
+            DateError::InvalidDay(day) => write!(f, "enter a valid day, Err d = {}", day),
+            DateError::InvalidGregorianDate => write!(f, "Wrong gegorean date foramt"),
+            DateError::OutOfRange => write!(
+                f,
+                "the handled hijri dates are 1356-01-01 to 1500-12-30 (1937-03-14 to 2077-11-16)"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateError {}
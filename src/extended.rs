@@ -0,0 +1,157 @@
+use crate::umalqura::month_index;
+use crate::{julian_day_number, tabular, DayNumber, HijriDate};
+
+// days handled by the extended dates, the gregorian years -262143 to 262142
+pub(crate) const MIN_JDN: i64 = -94_024_704;
+pub(crate) const MAX_JDN: i64 = 97_466_824;
+
+/// Dates outside the Umm al-Qura table.
+///
+/// The extended constructors use the table when it handles the date and fall back to the
+/// arithmetic calendar (see the `tabular` module) before and after it. The dates they return
+/// stay extended: adding a duration or moving to another month can leave the table, while the
+/// same operations on a date built with [`HijriDate::from_hijri`] fail past
+/// [`HijriDate::MIN`] and [`HijriDate::MAX`].
+///
+/// The limits of the table line up with the arithmetic calendar: 1355-12-30 is the day
+/// before [`HijriDate::MIN`] and 1501-01-01 the day after [`HijriDate::MAX`].
+///
+/// ```rust
+/// # #[cfg(feature = "chrono")]
+/// # {
+/// use hijri_date::{Duration, HijriDate};
+///
+/// // the Hijra, 1 Muharram 1 AH
+/// let hd = HijriDate::from_hijri_extended(1, 1, 1).unwrap();
+/// assert_eq!(hd.to_jdn(), 1_948_440);
+/// assert_eq!(hd.to_julian(), (622, 7, 16));
+/// assert!(hd.is_tabular());
+///
+/// let hd = HijriDate::from_gr_extended(2100, 1, 1).unwrap();
+/// assert_eq!(hd.format("%Y-%m-%d"), "1523-10-19");
+/// assert_eq!((hd + Duration::days(30)).format("%Y-%m-%d"), "1523-11-20");
+/// # }
+/// ```
+impl HijriDate {
+    /// get data from hijri date, using the arithmetic calendar outside the table.
+    pub fn from_hijri_extended(year: i32, month: usize, day: usize) -> Result<HijriDate, String> {
+        if !(1..=12).contains(&month) {
+            bail!("enter a valid month, Err m = {}", month);
+        }
+        if !(1..=30).contains(&day) {
+            bail!("enter a valid day, Err d = {}", day);
+        }
+        if in_table(year, month) {
+            return HijriDate::from_hijri(year, month, day).map(HijriDate::extended);
+        }
+
+        let month_len = tabular::month_len(year as i64, month);
+        if day > month_len {
+            bail!(
+                "enter a valid day, {}-{} has {} days, Err d = {}",
+                year,
+                month,
+                month_len,
+                day
+            );
+        }
+        let day_number = DayNumber::from_jdn(tabular::to_jdn(year as i64, month, day));
+        HijriDate::build(year, month, day, month_len, day_number)
+    }
+
+    /// get data from gregorian date, using the arithmetic calendar outside the table.
+    pub fn from_gr_extended(
+        year_gr: i32,
+        month_gr: usize,
+        day_gr: usize,
+    ) -> Result<HijriDate, String> {
+        match DayNumber::from_gregorian(year_gr, month_gr as u32, day_gr as u32) {
+            Some(day_number) => HijriDate::from_day_number_extended(day_number),
+            None => bail!("Wrong gegorean date foramt"),
+        }
+    }
+
+    /// get data from a julian (Old Style) calendar date, using the arithmetic calendar outside
+    /// the table.
+    pub fn from_julian_extended(year: i32, month: usize, day: usize) -> Result<HijriDate, String> {
+        HijriDate::from_day_number_extended(julian_day_number(year, month, day)?)
+    }
+
+    /// get data from a day number, using the arithmetic calendar outside the table.
+    pub fn from_day_number_extended(day_number: DayNumber) -> Result<HijriDate, String> {
+        if HijriDate::MIN.day_number <= day_number && day_number <= HijriDate::MAX.day_number {
+            return HijriDate::from_day_number(day_number).map(HijriDate::extended);
+        }
+
+        if !(MIN_JDN..=MAX_JDN).contains(&day_number.jdn()) {
+            bail!("julian day number {} is out of range", day_number.jdn());
+        }
+        let (year, month, day, month_len) = tabular::from_jdn(day_number.jdn());
+        // the hijri years of the range fit in an i32
+        HijriDate::build(year as i32, month, day, month_len, day_number)
+    }
+
+    /// Whether the date comes from the arithmetic calendar instead of the Umm al-Qura table
+    pub fn is_tabular(&self) -> bool {
+        !in_table(self.year, self.month)
+    }
+
+    /// Date of the same kind (extended or not) as this one
+    pub(crate) fn with_day_number(&self, day_number: DayNumber) -> Result<HijriDate, String> {
+        if self.extended {
+            HijriDate::from_day_number_extended(day_number)
+        } else {
+            HijriDate::from_day_number(day_number)
+        }
+    }
+
+    /// Date of the same kind (extended or not) as this one
+    pub(crate) fn with_hijri(
+        &self,
+        year: i32,
+        month: usize,
+        day: usize,
+    ) -> Result<HijriDate, String> {
+        if self.extended {
+            HijriDate::from_hijri_extended(year, month, day)
+        } else {
+            HijriDate::from_hijri(year, month, day)
+        }
+    }
+
+    pub(crate) fn extended(self) -> HijriDate {
+        HijriDate {
+            extended: true,
+            ..self
+        }
+    }
+
+    // extended date from the arithmetic calendar
+    fn build(
+        year: i32,
+        month: usize,
+        day: usize,
+        month_len: usize,
+        day_number: DayNumber,
+    ) -> Result<HijriDate, String> {
+        if !(MIN_JDN..=MAX_JDN).contains(&day_number.jdn()) {
+            bail!("julian day number {} is out of range", day_number.jdn());
+        }
+        let (year_gr, month_gr, day_gr) = day_number.gregorian();
+        Ok(HijriDate {
+            day,
+            month,
+            month_len,
+            year,
+            day_gr: day_gr as usize,
+            month_gr: month_gr as usize,
+            year_gr,
+            day_number,
+            extended: true,
+        })
+    }
+}
+
+fn in_table(year: i32, month: usize) -> bool {
+    year > 0 && month_index(year as usize, month).is_some()
+}
@@ -0,0 +1,110 @@
+use crate::recurrence::Recurrence;
+use crate::{HijriDate, HijriMonth, Locale};
+
+/// Relative descriptions of dates, like `in 12 days` or `منذ يومين`.
+///
+/// The largest unit with a non zero count is used, weeks for 14 days or more in the same
+/// month, and the count is truncated.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriMonth, Locale};
+///
+/// let today = HijriDate::from_hijri(1445, 8, 9).unwrap();
+/// let ramadan = HijriDate::from_hijri(1445, 9, 1).unwrap();
+/// assert_eq!(ramadan.humanize(&today, Locale::English), "in 3 weeks");
+/// assert_eq!(today.humanize(&ramadan, Locale::Arabic), "منذ 3 أسابيع");
+///
+/// // countdown to the next 1 Ramadan
+/// assert_eq!(today.countdown(HijriMonth::Ramadan, 1), Some(21));
+/// ```
+impl HijriDate {
+    /// Describes this date relative to `other`
+    pub fn humanize(&self, other: &HijriDate, locale: Locale) -> String {
+        let days = self.day_number - other.day_number;
+        let period = other.until(self);
+        let (count, unit) = if period.years != 0 {
+            (period.years, Unit::Year)
+        } else if period.months != 0 {
+            (period.months, Unit::Month)
+        } else if days.abs() >= 14 {
+            (period.days / 7, Unit::Week)
+        } else {
+            (period.days, Unit::Day)
+        };
+
+        match (locale, days) {
+            (Locale::English, 0) => "today".to_string(),
+            (Locale::English, 1) => "tomorrow".to_string(),
+            (Locale::English, -1) => "yesterday".to_string(),
+            (Locale::English, d) if d > 0 => format!("in {}", unit.english(count)),
+            (Locale::English, _) => format!("{} ago", unit.english(-count)),
+            (Locale::Arabic, 0) => "اليوم".to_string(),
+            (Locale::Arabic, 1) => "غداً".to_string(),
+            (Locale::Arabic, -1) => "أمس".to_string(),
+            (Locale::Arabic, d) if d > 0 => format!("بعد {}", unit.arabic(count)),
+            (Locale::Arabic, _) => format!("منذ {}", unit.arabic(-count)),
+        }
+    }
+
+    /// Describes this date relative to [`HijriDate::today`]
+    pub fn humanize_from_today(&self, locale: Locale) -> String {
+        self.humanize(&HijriDate::today(), locale)
+    }
+
+    /// Next `day` of `month` on or after this date, skipping the years where the day doesn't
+    /// exist. `None` past [`HijriDate::MAX`].
+    pub fn next_occurrence(&self, month: HijriMonth, day: usize) -> Option<HijriDate> {
+        Recurrence::yearly()
+            .by_month(&[month])
+            .by_day(&[day as i32])
+            .occurrences(self)
+            .next()
+    }
+
+    /// Number of days until the next `day` of `month`, see [`HijriDate::next_occurrence`]
+    pub fn countdown(&self, month: HijriMonth, day: usize) -> Option<i64> {
+        self.next_occurrence(month, day)
+            .map(|date| date.day_number - self.day_number)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Unit {
+    fn english(self, count: i32) -> String {
+        let name = match self {
+            Unit::Day => "day",
+            Unit::Week => "week",
+            Unit::Month => "month",
+            Unit::Year => "year",
+        };
+        if count == 1 {
+            format!("1 {}", name)
+        } else {
+            format!("{} {}s", count, name)
+        }
+    }
+
+    // counted noun in the genitive, as it follows a preposition
+    fn arabic(self, count: i32) -> String {
+        let (singular, dual, plural, accusative) = match self {
+            Unit::Day => ("يوم", "يومين", "أيام", "يوماً"),
+            Unit::Week => ("أسبوع", "أسبوعين", "أسابيع", "أسبوعاً"),
+            Unit::Month => ("شهر", "شهرين", "أشهر", "شهراً"),
+            Unit::Year => ("سنة", "سنتين", "سنوات", "سنة"),
+        };
+        match (count, count % 100) {
+            (1, _) => singular.to_string(),
+            (2, _) => dual.to_string(),
+            (_, 3..=10) => format!("{} {}", count, plural),
+            (_, 11..=99) => format!("{} {}", count, accusative),
+            _ => format!("{} {}", count, singular),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Ramadan timetable (imsakiyah).
+//!
+//! ```rust
+//! use hijri_date::Locale;
+//! use hijri_date::imsakiyah::Imsakiyah;
+//! use hijri_date::prayer_times::{Location, Params};
+//!
+//! let mecca = Location::new(21.4225, 39.8262, 3.0);
+//! let imsakiyah = Imsakiyah::new(1445, &mecca, &Params::default()).unwrap();
+//! assert_eq!(imsakiyah.days.len(), 30);
+//! println!("{}", imsakiyah.to_markdown(Locale::English));
+//! ```
+
+use crate::prayer_times::{Location, Params, PrayerTimes};
+use crate::HijriMonth::Ramadan;
+use crate::{HijriDate, Locale};
+use chrono::{Duration, NaiveTime, Timelike};
+
+/// Minutes between imsak and fajr.
+pub const IMSAK_MINUTES: i64 = 10;
+
+const COLUMNS: usize = 10;
+
+/// One day of the timetable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImsakiyahDay {
+    pub date: HijriDate,
+    pub imsak: NaiveTime,
+    pub times: PrayerTimes,
+}
+
+/// Prayer times of every day of Ramadan of a hijri year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Imsakiyah {
+    pub year: i32,
+    pub days: Vec<ImsakiyahDay>,
+}
+
+impl Imsakiyah {
+    /// Timetable of Ramadan of the given hijri year at the given location.
+    pub fn new(year: i32, location: &Location, params: &Params) -> Result<Imsakiyah, String> {
+        let month_len = HijriDate::from_hijri(year, Ramadan.number(), 1)?.month_len();
+
+        let mut days = Vec::with_capacity(month_len);
+        for day in 1..=month_len {
+            let date = HijriDate::from_hijri(year, Ramadan.number(), day)?;
+            let times = date.prayer_times(location, params)?;
+            let imsak = times.fajr - Duration::minutes(IMSAK_MINUTES);
+            days.push(ImsakiyahDay { date, imsak, times });
+        }
+
+        Ok(Imsakiyah { year, days })
+    }
+
+    /// Renders the timetable as CSV, with a header line.
+    pub fn to_csv(&self, locale: Locale) -> String {
+        let mut csv = header(locale).join(",");
+        csv.push('\n');
+        for row in self.rows(locale) {
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Renders the timetable as a Markdown table.
+    pub fn to_markdown(&self, locale: Locale) -> String {
+        let mut md = format!("| {} |\n", header(locale).join(" | "));
+        md.push_str(&format!("|{}\n", "---|".repeat(COLUMNS)));
+        for row in self.rows(locale) {
+            md.push_str(&format!("| {} |\n", row.join(" | ")));
+        }
+        md
+    }
+
+    /// Renders the timetable as a standalone HTML page, right to left for [`Locale::Arabic`].
+    pub fn to_html(&self, locale: Locale) -> String {
+        let (lang, dir) = match locale {
+            Locale::Arabic => ("ar", "rtl"),
+            Locale::English => ("en", "ltr"),
+        };
+        let title = self.title(locale);
+
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html lang=\"{}\" dir=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{}</title>\n<style>\n\
+             table {{ border-collapse: collapse; }}\n\
+             th, td {{ border: 1px solid #999; padding: 0.25em 0.75em; text-align: center; }}\n\
+             </style>\n</head>\n<body>\n<h1>{}</h1>\n<table>\n<thead>\n<tr>",
+            lang, dir, title, title
+        );
+        for cell in header(locale).iter() {
+            html.push_str(&format!("<th>{}</th>", cell));
+        }
+        html.push_str("</tr>\n</thead>\n<tbody>\n");
+        for row in self.rows(locale) {
+            html.push_str("<tr>");
+            for cell in row.iter() {
+                html.push_str(&format!("<td>{}</td>", cell));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n</table>\n</body>\n</html>\n");
+        html
+    }
+
+    fn title(&self, locale: Locale) -> String {
+        match locale {
+            Locale::Arabic => format!("إمساكية رمضان {} هـ", self.year),
+            Locale::English => format!("Ramadan {} AH timetable", self.year),
+        }
+    }
+
+    fn rows(&self, locale: Locale) -> Vec<[String; COLUMNS]> {
+        let time = |t: NaiveTime| format!("{:02}:{:02}", t.hour(), t.minute());
+        self.days
+            .iter()
+            .map(|day| {
+                let times = &day.times;
+                [
+                    day.date.day().to_string(),
+                    day.date.weekday().name(locale).to_string(),
+                    day.date.date_gr().to_string(),
+                    time(day.imsak),
+                    time(times.fajr),
+                    time(times.sunrise),
+                    time(times.dhuhr),
+                    time(times.asr),
+                    time(times.maghrib),
+                    time(times.isha),
+                ]
+            })
+            .collect()
+    }
+}
+
+fn header(locale: Locale) -> [&'static str; COLUMNS] {
+    match locale {
+        Locale::Arabic => [
+            "رمضان",
+            "اليوم",
+            "التاريخ",
+            "الإمساك",
+            "الفجر",
+            "الشروق",
+            "الظهر",
+            "العصر",
+            "المغرب",
+            "العشاء",
+        ],
+        Locale::English => [
+            "Ramadan", "Day", "Date", "Imsak", "Fajr", "Sunrise", "Dhuhr", "Asr", "Maghrib", "Isha",
+        ],
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{DayNumber, HijriDate};
+
+/// Range of hijri dates, either closed (`start..=end`) or half-open (`start..end`).
+///
+/// Operations between intervals keep the variant of `self`.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriInterval};
+///
+/// // gregorian range split at the hijri month boundaries
+/// let start = HijriDate::from_gr(2024, 3, 1).unwrap();
+/// let end = HijriDate::from_gr(2024, 4, 30).unwrap();
+/// let months = HijriInterval::closed(start, end).unwrap().split_by_hijri_month();
+/// assert_eq!(months.len(), 3);
+/// assert_eq!(months[1].start().format("%Y-%m-%d"), "1445-9-1");
+/// assert_eq!(months[1].len_days(), 30);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HijriInterval {
+    start: HijriDate,
+    end: HijriDate,
+    closed: bool,
+}
+
+impl HijriInterval {
+    /// Interval from `start` to `end`, both included
+    pub fn closed(start: HijriDate, end: HijriDate) -> Result<HijriInterval, String> {
+        if end < start {
+            bail!(
+                "end of the interval {} is before its start {}",
+                end.format("%Y-%m-%d"),
+                start.format("%Y-%m-%d")
+            );
+        }
+        Ok(Self {
+            start,
+            end,
+            closed: true,
+        })
+    }
+
+    /// Interval from `start` included to `end` excluded, empty if they are equal
+    pub fn half_open(start: HijriDate, end: HijriDate) -> Result<HijriInterval, String> {
+        if end < start {
+            bail!(
+                "end of the interval {} is before its start {}",
+                end.format("%Y-%m-%d"),
+                start.format("%Y-%m-%d")
+            );
+        }
+        Ok(Self {
+            start,
+            end,
+            closed: false,
+        })
+    }
+
+    pub fn start(&self) -> &HijriDate {
+        &self.start
+    }
+
+    /// End of the interval, included only if the interval is closed
+    pub fn end(&self) -> &HijriDate {
+        &self.end
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len_days() == 0
+    }
+
+    /// Number of days in the interval
+    pub fn len_days(&self) -> usize {
+        (self.end_excl() - self.start.day_number()) as usize
+    }
+
+    pub fn contains(&self, date: &HijriDate) -> bool {
+        self.start.day_number() <= date.day_number() && date.day_number() < self.end_excl()
+    }
+
+    /// Whether the intervals have at least one day in common
+    pub fn overlaps(&self, other: &HijriInterval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Days in both intervals, `None` if there is none
+    pub fn intersection(&self, other: &HijriInterval) -> Option<HijriInterval> {
+        let start = self.start.day_number().max(other.start.day_number());
+        let end_excl = self.end_excl().min(other.end_excl());
+        if start >= end_excl {
+            return None;
+        }
+        self.with_days(start, end_excl)
+    }
+
+    /// Days in either interval, `None` if they neither overlap nor touch
+    pub fn union(&self, other: &HijriInterval) -> Option<HijriInterval> {
+        let start = self.start.day_number().min(other.start.day_number());
+        let end_excl = self.end_excl().max(other.end_excl());
+        let gap_start = self.start.day_number().max(other.start.day_number());
+        let gap_end = self.end_excl().min(other.end_excl());
+        if gap_start > gap_end {
+            return None;
+        }
+        self.with_days(start, end_excl)
+    }
+
+    /// Splits the interval at the start of every hijri month
+    pub fn split_by_hijri_month(&self) -> Vec<HijriInterval> {
+        self.split(|date| date.last_day_of_month())
+    }
+
+    /// Splits the interval at the start of every hijri year
+    pub fn split_by_hijri_year(&self) -> Vec<HijriInterval> {
+        self.split(|date| date.last_day_of_year())
+    }
+
+    fn split(&self, last_day: impl Fn(&HijriDate) -> HijriDate) -> Vec<HijriInterval> {
+        let mut parts = Vec::new();
+        let mut start = self.start.clone();
+        loop {
+            let end_excl = (last_day(&start).day_number() + 1).min(self.end_excl());
+            if start.day_number() >= end_excl {
+                break;
+            }
+            // shouldn't fail, the part is inside self
+            parts.push(self.with_days(start.day_number(), end_excl).unwrap());
+            start = match self.start.with_day_number(end_excl) {
+                Ok(start) => start,
+                Err(_) => break,
+            };
+        }
+        parts
+    }
+
+    fn end_excl(&self) -> DayNumber {
+        if self.closed {
+            self.end.day_number() + 1
+        } else {
+            self.end.day_number()
+        }
+    }
+
+    /// Interval of the same variant as self, a closed one if the exclusive end isn't handled
+    fn with_days(&self, start: DayNumber, end_excl: DayNumber) -> Option<HijriInterval> {
+        let start = self.start.with_day_number(start).ok()?;
+        if !self.closed {
+            if let Ok(end) = self.start.with_day_number(end_excl) {
+                return Some(Self {
+                    start,
+                    end,
+                    closed: false,
+                });
+            }
+        }
+        let end = self.start.with_day_number(end_excl - 1).ok()?;
+        Some(Self {
+            start,
+            end,
+            closed: true,
+        })
+    }
+}
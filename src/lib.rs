@@ -1,5 +1,7 @@
 // In the name of Allah
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 //! # HijriDate-rs
 //!
 //! Convert between hijri and gregorian date.
@@ -7,13 +9,18 @@
 //! The algorithm used to convert between dates is limited to:
 //!
 //! ```text
-//! minimum handled hijri year = 1356
-//! maximum handled hijri year = 1500
+//! minimum handled hijri date = 1356-01-01
+//! maximum handled hijri date = 1500-12-30
 //!
-//! minimum handled gregorian year = 1938
-//! maximum handled gregorian year = 2076
+//! minimum handled gregorian date = 1937-03-14
+//! maximum handled gregorian date = 2077-11-16
 //! ```
 //!
+//! These limits are available as [`HijriDate::MIN`], [`HijriDate::MAX`],
+//! [`HijriDate::MIN_GR`] and [`HijriDate::MAX_GR`]. The extended constructors, like
+//! [`HijriDate::from_hijri_extended`], fall back to the arithmetic islamic calendar outside of
+//! them.
+//!
 //! ## Usage
 //!
 //! *convert to gregorian*
@@ -56,146 +63,382 @@
 //!  *substract duration from a day*
 //!
 //! ```rust
+//! # #[cfg(feature = "chrono")]
+//! # {
 //! use hijri_date::{Duration,HijriDate};
 //!
 //! let hd_1 = HijriDate::from_hijri(1420, 06, 15).unwrap();
 //! let hd_2 = HijriDate::from_hijri(1420, 05, 29).unwrap();
 //! assert_eq!(hd_1 - Duration::days(16), hd_2);
+//! # }
 //! ```
 //!
 //!  *substract a day from an other to get a duration*
 //!
 //! ```rust
+//! # #[cfg(feature = "chrono")]
+//! # {
 //! use hijri_date::{Duration,HijriDate};
 //!
 //! let hd_1 = HijriDate::from_hijri(1358, 06, 15).unwrap();
 //! let hd_2 = HijriDate::from_hijri(1358, 06, 7).unwrap();
 //! assert_eq!(hd_1-hd_2,Duration::days(8));
+//! # }
 //! ```
 //!
 
+#[cfg(feature = "std")]
 #[macro_use]
 mod utils;
 
+#[cfg(feature = "std")]
+use month::GREGORIAN_MONTHS;
+#[cfg(feature = "std")]
 use umalqura::*;
 mod umalqura;
 mod umalqura_array;
 
+mod compact;
+mod day_number;
+#[cfg(feature = "std")]
+mod era;
+mod error;
+#[cfg(feature = "std")]
+mod extended;
+#[cfg(feature = "std")]
+mod humanize;
+#[cfg(feature = "std")]
+mod interval;
+mod literal;
+mod month;
+#[cfg(feature = "std")]
+mod navigation;
+#[cfg(feature = "std")]
+mod numerals;
+#[cfg(feature = "std")]
+mod period;
+#[cfg(feature = "std")]
+mod solar;
+#[cfg(all(feature = "std", any(feature = "sqlx", feature = "postgres")))]
+mod sql;
+#[cfg(feature = "std")]
+mod style;
+#[cfg(feature = "std")]
+mod tabular;
+#[cfg(feature = "std")]
+mod week;
+mod weekday;
+
+#[cfg(feature = "std")]
+pub mod bidi;
+#[cfg(feature = "std")]
+pub mod bucket;
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub mod imsakiyah;
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub mod prayer_times;
+#[cfg(feature = "std")]
+pub mod recurrence;
+#[cfg(feature = "std")]
+pub mod words;
+
+pub use compact::CompactHijriDate;
+pub use day_number::DayNumber;
+#[cfg(feature = "std")]
+pub use era::Era;
+pub use error::DateError;
+#[cfg(feature = "std")]
+pub use interval::HijriInterval;
+pub use month::{HijriMonth, HijriYearMonth};
+#[cfg(feature = "std")]
+pub use numerals::NumberingSystem;
+#[cfg(feature = "std")]
+pub use period::HijriPeriod;
+#[cfg(feature = "std")]
+pub use solar::{SolarAlgorithm, SolarHijriDate, SolarLocale};
+#[cfg(all(feature = "std", any(feature = "sqlx", feature = "postgres")))]
+pub use sql::HijriText;
+#[cfg(feature = "std")]
+pub use style::{DateFormat, FormatStyle, Styled};
+#[cfg(feature = "std")]
+pub use week::WeekNumbering;
+pub use weekday::HijriWeekday;
+
+#[cfg(feature = "chrono")]
 pub use chrono::Duration;
-use chrono::{NaiveDate, Utc};
+#[cfg(all(feature = "std", feature = "chrono"))]
+use chrono::NaiveDate;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
 use wasm_bindgen::prelude::*;
 
-use std::cmp::Ordering;
-use std::fmt;
-use std::ops::Index;
+#[cfg(all(
+    feature = "std",
+    any(feature = "chrono", feature = "time", feature = "jiff")
+))]
 use std::ops::{Add, Sub};
-
-struct Map<T, U, const N: usize>([(T, U); N]);
-
-impl<T: PartialEq, U, const N: usize> Index<T> for Map<T, U, N> {
-    type Output = U;
-
-    fn index(&self, t: T) -> &Self::Output {
-        &self.0.iter().find(|elem| elem.0 == t).unwrap().1
-    }
+#[cfg(feature = "std")]
+use std::{cmp::Ordering, convert::TryFrom, fmt, str::FromStr};
+
+/// Language used for names and generated text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    Arabic,
+    English,
 }
 
-static MONTH_DICT: &Map<usize, &str, 12> = &Map([
-    (1, "محرم"),
-    (2, "صفر"),
-    (3, "ربيع الأول"),
-    (4, "ربيع الثاني"),
-    (5, "جمادي الأولى"),
-    (6, "جمادي الآخرة"),
-    (7, "رجب"),
-    (8, "شعبان"),
-    (9, "رمضان"),
-    (10, "شوال"),
-    (11, "ذو القعدة"),
-    (12, "ذو الحجة"),
-]);
-
-static DAY_DICT: &Map<&str, &str, 7> = &Map([
-    ("Saturday", "السبت"),
-    ("Sunday", "الاحد"),
-    ("Monday", "الاثنين"),
-    ("Tuesday", "الثلاثاء"),
-    ("Wednesday", "الاربعاء"),
-    ("Thursday", "الخميس"),
-    ("Friday", "الجمعة"),
-]);
 ///Main structure.
 ///  - Contains numeric value of hijri and gregorian dates plus hijri month and day names.
 ///  - Hijri names dosent have suffix, example (day,month,year,..)
 ///  - Gregorian names are denoted with `gr` or `en` suffix.
+#[cfg(feature = "std")]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct HijriDate {
     //hijri
     day: usize,
     month: usize,
     month_len: usize,
-    year: usize,
-    day_name: String,
-    month_name: String,
+    year: i32,
 
     //gregorian
     day_gr: usize,
     month_gr: usize,
-    year_gr: usize,
-    day_name_en: String,
-    month_name_en: String,
-    // needed to ease trait impl(add,sub,partialeq..)
-    date_gr: NaiveDate,
+    year_gr: i32,
+    day_number: DayNumber,
+    // falls back to the arithmetic calendar outside the table
+    extended: bool,
 }
 
+/// Dates are equal if they are the same day with the same hijri date, whether they are
+/// extended or not
+#[cfg(feature = "std")]
+impl PartialEq for HijriDate {
+    fn eq(&self, other: &HijriDate) -> bool {
+        (self.day_number, self.year, self.month, self.day)
+            == (other.day_number, other.year, other.month, other.day)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for HijriDate {}
+
+/// Full arabic date with the gregorian date, see [`DateFormat`] and [`HijriDate::styled`]
+/// for other formats. The alternate flag (`{:#}`) isolates it as right-to-left text.
+#[cfg(feature = "std")]
 impl fmt::Display for HijriDate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "{}", &self.format("%Y %M %D"))?;
-        writeln!(f, "{}", &self.format("%gD %gM %gY"))?;
-
-        Ok(())
+        self.styled(DateFormat::default()).fmt(f)
     }
 }
 
+/// Adds the whole days of the duration
+#[cfg(all(feature = "std", feature = "chrono"))]
 impl Add<Duration> for HijriDate {
     type Output = HijriDate;
 
     fn add(self, other: Duration) -> HijriDate {
         // shouldn't fail
-        HijriDate::chrno_to_hijri(self.date_gr + other).unwrap()
+        self.with_day_number(self.day_number + other.num_days())
+            .unwrap()
     }
 }
 
+/// Subtracts the whole days of the duration
+#[cfg(all(feature = "std", feature = "chrono"))]
 impl Sub<Duration> for HijriDate {
     type Output = HijriDate;
 
     fn sub(self, other: Duration) -> HijriDate {
         // shouldn't fail
-        HijriDate::chrno_to_hijri(self.date_gr - other).unwrap()
+        self.with_day_number(self.day_number - other.num_days())
+            .unwrap()
     }
 }
 
+#[cfg(all(feature = "std", feature = "chrono"))]
 impl Sub<HijriDate> for HijriDate {
     type Output = Duration;
 
     fn sub(self, other: HijriDate) -> Duration {
-        self.date_gr - other.date_gr
+        Duration::days(self.day_number - other.day_number)
+    }
+}
+
+/// Adds the whole days of the duration
+#[cfg(all(feature = "std", feature = "time"))]
+impl Add<time::Duration> for HijriDate {
+    type Output = HijriDate;
+
+    fn add(self, other: time::Duration) -> HijriDate {
+        // shouldn't fail
+        self.with_day_number(self.day_number + other.whole_days())
+            .unwrap()
+    }
+}
+
+/// Subtracts the whole days of the duration
+#[cfg(all(feature = "std", feature = "time"))]
+impl Sub<time::Duration> for HijriDate {
+    type Output = HijriDate;
+
+    fn sub(self, other: time::Duration) -> HijriDate {
+        // shouldn't fail
+        self.with_day_number(self.day_number - other.whole_days())
+            .unwrap()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "time"))]
+impl TryFrom<time::Date> for HijriDate {
+    type Error = String;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        HijriDate::from_day_number(date.into())
+    }
+}
+
+/// Fails only for extended dates out of the range of `time`
+#[cfg(all(feature = "std", feature = "time"))]
+impl TryFrom<&HijriDate> for time::Date {
+    type Error = time::error::ComponentRange;
+
+    fn try_from(date: &HijriDate) -> Result<Self, Self::Error> {
+        time::Date::try_from(date.day_number)
+    }
+}
+
+/// Adds the years and months of the span as hijri years and months, keeping the day clamped to
+/// the length of the month like [`HijriDate::until`], then its weeks, days and the whole days of
+/// its time units
+#[cfg(all(feature = "std", feature = "jiff"))]
+impl Add<jiff::Span> for HijriDate {
+    type Output = HijriDate;
+
+    fn add(self, other: jiff::Span) -> HijriDate {
+        // shouldn't fail
+        self.checked_add_span(other).unwrap()
+    }
+}
+
+/// Subtracts the span, see the addition
+#[cfg(all(feature = "std", feature = "jiff"))]
+impl Sub<jiff::Span> for HijriDate {
+    type Output = HijriDate;
+
+    fn sub(self, other: jiff::Span) -> HijriDate {
+        // shouldn't fail
+        self.checked_add_span(other.negate()).unwrap()
     }
 }
 
+#[cfg(all(feature = "std", feature = "jiff"))]
+impl TryFrom<jiff::civil::Date> for HijriDate {
+    type Error = String;
+
+    fn try_from(date: jiff::civil::Date) -> Result<Self, Self::Error> {
+        HijriDate::from_day_number(date.into())
+    }
+}
+
+/// Hijri date of the day in the time zone of the zoned datetime
+#[cfg(all(feature = "std", feature = "jiff"))]
+impl TryFrom<&jiff::Zoned> for HijriDate {
+    type Error = String;
+
+    fn try_from(zoned: &jiff::Zoned) -> Result<Self, Self::Error> {
+        HijriDate::from_day_number(zoned.into())
+    }
+}
+
+/// Fails only for extended dates out of the range of `jiff`
+#[cfg(all(feature = "std", feature = "jiff"))]
+impl TryFrom<&HijriDate> for jiff::civil::Date {
+    type Error = jiff::Error;
+
+    fn try_from(date: &HijriDate) -> Result<Self, Self::Error> {
+        jiff::civil::Date::try_from(date.day_number)
+    }
+}
+
+/// Hijri date of the day of a date of any ICU4X calendar
+#[cfg(all(feature = "std", feature = "icu_calendar"))]
+impl<A: icu_calendar::AsCalendar> TryFrom<&icu_calendar::Date<A>> for HijriDate {
+    type Error = String;
+
+    fn try_from(date: &icu_calendar::Date<A>) -> Result<Self, Self::Error> {
+        HijriDate::from_day_number(date.into())
+    }
+}
+
+/// Same day in the ICU4X Umm al-Qura calendar, its year, month and day can differ from this
+/// crate's table
+#[cfg(all(feature = "std", feature = "icu_calendar"))]
+impl From<&HijriDate> for icu_calendar::Date<icu_calendar::islamic::IslamicUmmAlQura> {
+    fn from(date: &HijriDate) -> Self {
+        date.to_icu_iso()
+            .to_calendar(icu_calendar::islamic::IslamicUmmAlQura::new())
+    }
+}
+
+/// Same day in the ICU4X arithmetical islamic calendar
+#[cfg(all(feature = "std", feature = "icu_calendar"))]
+impl From<&HijriDate> for icu_calendar::Date<icu_calendar::islamic::IslamicCivil> {
+    fn from(date: &HijriDate) -> Self {
+        date.to_icu_iso()
+            .to_calendar(icu_calendar::islamic::IslamicCivil::new())
+    }
+}
+
+#[cfg(feature = "std")]
 impl PartialOrd for HijriDate {
-    //use chrono to implement cmp
     fn partial_cmp(&self, other: &HijriDate) -> Option<Ordering> {
-        Some(self.date_gr.cmp(&other.date_gr))
+        Some(self.day_number.cmp(&other.day_number))
+    }
+}
+
+/// Parses a hijri `year-month-day`, separated by `-`, `/` or `.`, with latin, arabic-indic or
+/// persian digits. The date is extended (see [`HijriDate::from_hijri_extended`]) if it is
+/// followed by a hijri era, example `5-9-1 BH` or `1445/9/1 هـ`.
+#[cfg(feature = "std")]
+impl FromStr for HijriDate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = NumberingSystem::to_latin(s.trim());
+        let end = s
+            .find(|c: char| !(c.is_ascii_digit() || "-/. ".contains(c)))
+            .unwrap_or(s.len());
+        let (date, era) = (s[..end].trim(), s[end..].trim());
+        let parts: Vec<u32> = date
+            .split(&['-', '/', '.'][..])
+            .map(|part| part.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("Wrong hijri date format, expected year-month-day: {}", s))?;
+        let (year, month, day) = match parts[..] {
+            [year, month, day] => (year, month as usize, day as usize),
+            _ => bail!("Wrong hijri date format, expected year-month-day: {}", s),
+        };
+
+        let out_of_range = || format!("year {} is out of range", year);
+        if era.is_empty() {
+            let year = i32::try_from(year).map_err(|_| out_of_range())?;
+            return HijriDate::from_hijri(year, month, day);
+        }
+        match era.parse()? {
+            era @ Era::AH | era @ Era::BH => {
+                let year = era.astronomical_year(year).ok_or_else(out_of_range)?;
+                HijriDate::from_hijri_extended(year, month, day)
+            }
+            _ => bail!("expected a hijri era, input: {}", era),
+        }
     }
 }
 
 // #[cfg(target_arch = "wasm32")]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 // #[wasm_bindgen]
+#[cfg(feature = "std")]
 impl HijriDate {
     // Define getter methods for various attributes of HijriDate
 
@@ -214,19 +457,19 @@ impl HijriDate {
         self.month_len
     }
 
-    /// Get the year of the Hijri date
-    pub fn year(&self) -> usize {
+    /// Get the year of the Hijri date, year 0 being 1 BH (see [`Era`])
+    pub fn year(&self) -> i32 {
         self.year
     }
 
     /// Get the name of the day in the Hijri calendar
     pub fn day_name(&self) -> String {
-        self.day_name.clone()
+        self.weekday().name(Locale::Arabic).to_string()
     }
 
     /// Get the name of the month in the Hijri calendar
     pub fn month_name(&self) -> String {
-        self.month_name.clone()
+        self.month_enum().name(Locale::Arabic).to_string()
     }
 
     /// Get the day of the week (Gregorian) corresponding to the Hijri date
@@ -239,19 +482,19 @@ impl HijriDate {
         self.month_gr
     }
 
-    /// Get the year (Gregorian) corresponding to the Hijri date
-    pub fn year_gr(&self) -> usize {
+    /// Get the year (Gregorian) corresponding to the Hijri date, year 0 being 1 BCE
+    pub fn year_gr(&self) -> i32 {
         self.year_gr
     }
 
     /// Get the English name of the day in the Hijri calendar
     pub fn day_name_en(&self) -> String {
-        self.day_name_en.clone()
+        self.weekday().name(Locale::English).to_string()
     }
 
     /// Get the English name of the month in the Hijri calendar
     pub fn month_name_en(&self) -> String {
-        self.month_name_en.clone()
+        GREGORIAN_MONTHS[self.month_gr - 1].to_string()
     }
 
     // Define a JavaScript-compatible method to convert the HijriDate to a formatted string
@@ -263,98 +506,19 @@ impl HijriDate {
     }
 
     /// get data from hijri date
-    pub fn from_hijri(year: usize, month: usize, day: usize) -> Result<HijriDate, String> {
-        valid_hijri_date(year, month, day)?;
-        let month_name = MONTH_DICT[month].to_string();
-        let (year_gr, month_gr, day_gr) = hijri_to_gregorian(year, month, day);
-        let date_gr = format!("{}-{}-{}", year_gr, month_gr, day_gr);
-        let date_gr = if let Ok(date_gr) = NaiveDate::parse_from_str(&date_gr, "%Y-%m-%d") {
-            date_gr
-        } else {
-            bail!("Wrong gegorean date foramt")
-        };
-        let day_name_en = date_gr.format("%A").to_string();
-        let day_name = DAY_DICT[&day_name_en].to_string();
-        let month_name_en = date_gr.format("%B").to_string();
-        let (_, _, _, month_len) = gegorean_to_hijri(year_gr, month_gr, day_gr);
-
-        Ok(Self {
-            day,
-            month,
-            month_len,
-            year,
-            day_name,
-            month_name,
-
-            //gregorian
-            day_gr,
-            month_gr,
-            year_gr,
-            day_name_en,
-            month_name_en,
-            date_gr,
-        })
+    pub fn from_hijri(year: i32, month: usize, day: usize) -> Result<HijriDate, String> {
+        let index = valid_hijri_date(year, month, day)?;
+        Ok(Self::from_mcjdn(hijri_to_mcjdn(index, day)))
     }
     /// get data from gregorian date.
-    pub fn from_gr(year_gr: usize, month_gr: usize, day_gr: usize) -> Result<HijriDate, String> {
-        valid_greorian_date(year_gr, month_gr, day_gr)?;
-        let date_gr = format!("{}-{}-{}", year_gr, month_gr, day_gr);
-        let date_gr = if let Ok(date_gr) = NaiveDate::parse_from_str(&date_gr, "%Y-%m-%d") {
-            date_gr
-        } else {
-            bail!("Wrong gegorean date foramt")
-        };
-
-        let (year, month, day, month_len) = gegorean_to_hijri(year_gr, month_gr, day_gr);
-        let month_name = MONTH_DICT[month].to_string();
-
-        let day_name_en = date_gr.format("%A").to_string();
-        let day_name = DAY_DICT[day_name_en.as_str()].to_string();
-        let month_name_en = date_gr.format("%B").to_string();
-
-        Ok(Self {
-            //hijri
-            day,
-            month,
-            month_len,
-            year,
-            day_name,
-            month_name,
-
-            //gregorian
-            day_gr,
-            month_gr,
-            year_gr,
-            day_name_en,
-            month_name_en,
-            date_gr,
-        })
+    pub fn from_gr(year_gr: i32, month_gr: usize, day_gr: usize) -> Result<HijriDate, String> {
+        let day_number = valid_greorian_date(year_gr, month_gr, day_gr)?;
+        Self::from_day_number(day_number)
     }
     /// get data from today's date.
     pub fn today() -> Self {
-        let today = Utc::now().date_naive();
-
         // It shouldn't fail
-        Self::chrno_to_hijri(today).unwrap()
-    }
-
-    //helper method
-    fn chrno_to_hijri(date: NaiveDate) -> Result<Self, String> {
-        let (year_gr, month_gr, day_gr): (usize, usize, usize) = (
-            date.format("%Y")
-                .to_string()
-                .parse()
-                .map_err(|_| "Error parsing date")?,
-            date.format("%m")
-                .to_string()
-                .parse()
-                .map_err(|_| "Error parsing date")?,
-            date.format("%d")
-                .to_string()
-                .parse()
-                .map_err(|_| "Error parsing date")?,
-        );
-        HijriDate::from_gr(year_gr, month_gr, day_gr)
+        Self::from_day_number(DayNumber::today()).unwrap()
     }
 
     /// Returns a representation of HijriDate defined by the given formatter
@@ -362,67 +526,311 @@ impl HijriDate {
     /// ```text
     ///        hijri
     ///
-    ///     %Y              hijri_year
+    ///     %Y              hijri_year (astronomical, 0 being 1 BH)
+    ///     %y              hijri_year_of_era
+    ///     %E              hijri_era (AH or BH)
     ///     %m              hijri_month
     ///     %d              hijri_day
     ///     %D              hijri_day_name
     ///     %M              hijri_month_name
     ///     %l              hijri_month_len
+    ///     %wd             hijri_day_arabic_ordinal_words
+    ///     %wY             hijri_year_arabic_words
+    ///     %ed             hijri_day_english_ordinal_words
     ///
     ///        gregorian
     ///
-    ///     %gY             gregorian_year
+    ///     %gY             gregorian_year (astronomical, 0 being 1 BCE)
+    ///     %gy             gregorian_year_of_era
+    ///     %gE             gregorian_era (CE or BCE)
     ///     %gm             gregorian_month
     ///     %gd             gregorian_day
     ///     %gD             gregorian_day_name
     ///     %gM             gregorian_month_name
     /// ```
     pub fn format(&self, f: &str) -> String {
-        f.replace("%Y", &self.year.to_string())
-            .replace("%m", &self.month.to_string())
-            .replace("%d", &self.day.to_string())
-            .replace("%D", &self.day_name)
-            .replace("%M", &self.month_name)
-            .replace("%l", &self.month_len.to_string())
-            .replace("%gY", &self.year_gr.to_string())
-            .replace("%gm", &self.month_gr.to_string())
-            .replace("%gd", &self.day_gr.to_string())
-            .replace("%gD", &self.day_name_en)
-            .replace("%gM", &self.month_name_en)
+        self.format_with(f, NumberingSystem::Latin)
+    }
+}
+
+#[cfg(feature = "std")]
+impl HijriDate {
+    /// First day handled by the algorithm, 1356-01-01 (1937-03-14)
+    pub const MIN: HijriDate = HijriDate::from_mcjdn(MIN_MCJDN);
+
+    /// Last day handled by the algorithm, 1500-12-30 (2077-11-16)
+    pub const MAX: HijriDate = HijriDate::from_mcjdn(MAX_MCJDN);
+
+    /// Gregorian date of [`HijriDate::MIN`]
+    #[cfg(feature = "chrono")]
+    pub const MIN_GR: NaiveDate = HijriDate::MIN.date_gr();
+
+    /// Gregorian date of [`HijriDate::MAX`]
+    #[cfg(feature = "chrono")]
+    pub const MAX_GR: NaiveDate = HijriDate::MAX.date_gr();
+
+    /// Same as [`HijriDate::format`], with the numbers written in the given numbering system
+    pub fn format_with(&self, f: &str, numbering: NumberingSystem) -> String {
+        self.render(f, numbering, false)
+    }
+
+    /// Same as [`HijriDate::format_with`], with every arabic field wrapped in a right-to-left
+    /// isolate so that it doesn't reorder the text around it
+    pub fn format_isolated(&self, f: &str, numbering: NumberingSystem) -> String {
+        self.render(f, numbering, true)
+    }
+
+    fn render(&self, f: &str, numbering: NumberingSystem, isolate: bool) -> String {
+        let number = |n: &dyn ToString| numbering.apply(&n.to_string());
+        let arabic = |s: String| if isolate { bidi::isolate_rtl(&s) } else { s };
+        f.replace("%Y", &number(&self.year))
+            .replace("%y", &number(&self.year_of_era()))
+            .replace("%E", self.era().suffix(Locale::English))
+            .replace("%m", &number(&self.month))
+            .replace("%d", &number(&self.day))
+            .replace("%D", &arabic(self.day_name()))
+            .replace("%M", &arabic(self.month_name()))
+            .replace("%l", &number(&self.month_len))
+            .replace("%wd", &arabic(self.day_arabic_words()))
+            .replace("%wY", &arabic(self.year_arabic_words()))
+            .replace("%ed", &self.day_english_words())
+            .replace("%gY", &number(&self.year_gr))
+            .replace("%gy", &number(&self.year_of_era_gr()))
+            .replace("%gE", self.era_gr().suffix(Locale::English))
+            .replace("%gm", &number(&self.month_gr))
+            .replace("%gd", &number(&self.day_gr))
+            .replace("%gD", &self.day_name_en())
+            .replace("%gM", &self.month_name_en())
+    }
+
+    /// Get the month of the Hijri year as a [`HijriMonth`]
+    pub fn month_enum(&self) -> HijriMonth {
+        // month is always valid
+        HijriMonth::from_number(self.month).unwrap()
+    }
+
+    /// Get the day of the week
+    pub fn weekday(&self) -> HijriWeekday {
+        self.day_number.weekday()
+    }
+
+    /// get data from a day number.
+    pub fn from_day_number(day_number: DayNumber) -> Result<HijriDate, String> {
+        Ok(Self::from_mcjdn(Self::checked_mcjdn(day_number)?))
+    }
+
+    /// Modified Chronological Julian Day Number of a day handled by the table
+    pub(crate) fn checked_mcjdn(day_number: DayNumber) -> Result<usize, String> {
+        if day_number < HijriDate::MIN.day_number {
+            bail!(
+                "minimum handled julian day number is {}, input: {}",
+                HijriDate::MIN.to_jdn(),
+                day_number.jdn()
+            );
+        }
+        if day_number > HijriDate::MAX.day_number {
+            bail!(
+                "maximum handled julian day number is {}, input: {}",
+                HijriDate::MAX.to_jdn(),
+                day_number.jdn()
+            );
+        }
+        Ok((day_number.jdn() - MCJDN_EPOCH) as usize)
+    }
+
+    /// get data from a Julian Day Number.
+    pub fn from_jdn(jdn: i64) -> Result<HijriDate, String> {
+        Self::from_day_number(DayNumber::from_jdn(jdn))
+    }
+
+    /// get data from a Modified Julian Day.
+    pub fn from_mjd(mjd: i64) -> Result<HijriDate, String> {
+        Self::from_day_number(DayNumber::from_mjd(mjd))
+    }
+
+    /// get data from a Rata Die (days since 0000-12-31 gregorian).
+    pub fn from_rata_die(rd: i64) -> Result<HijriDate, String> {
+        Self::from_day_number(DayNumber::from_rata_die(rd))
+    }
+
+    /// get data from a julian (Old Style) calendar date, year 0 being 1 BCE.
+    pub fn from_julian(year: i32, month: usize, day: usize) -> Result<HijriDate, String> {
+        Self::from_day_number(julian_day_number(year, month, day)?)
+    }
+
+    /// Get the julian (Old Style) calendar (year, month, day) of the date, year 0 being 1 BCE
+    pub fn to_julian(&self) -> (i32, usize, usize) {
+        let (year, month, day) = self.day_number.julian();
+        (year, month as usize, day as usize)
+    }
+
+    /// Get the day number of the date, see [`DayNumber`]
+    pub fn day_number(&self) -> DayNumber {
+        self.day_number
+    }
+
+    /// Get the Julian Day Number of the date
+    pub fn to_jdn(&self) -> i64 {
+        self.day_number.jdn()
+    }
+
+    /// Get the Modified Julian Day of the date
+    pub fn to_mjd(&self) -> i64 {
+        self.day_number.mjd()
+    }
+
+    /// Get the Rata Die of the date
+    pub fn to_rata_die(&self) -> i64 {
+        self.day_number.rata_die()
+    }
+
+    /// Same day `months` months later (or earlier if negative), clamped to the length of the
+    /// resulting month. `None` if the resulting month isn't handled
+    pub(crate) fn add_months(&self, months: i32) -> Option<HijriDate> {
+        let total = self.year as i64 * 12 + self.month as i64 - 1 + months as i64;
+        let year = i32::try_from(total.div_euclid(12)).ok()?;
+        let month = total.rem_euclid(12) as usize + 1;
+        let first = self.with_hijri(year, month, 1).ok()?;
+        self.with_hijri(year, month, self.day.min(first.month_len))
+            .ok()
+    }
+
+    #[cfg(feature = "icu_calendar")]
+    fn to_icu_iso(&self) -> icu_calendar::Date<icu_calendar::Iso> {
+        // the extended range is in the one of icu_calendar
+        icu_calendar::Date::try_from(self.day_number).unwrap()
+    }
+
+    #[cfg(feature = "jiff")]
+    fn checked_add_span(&self, span: jiff::Span) -> Option<HijriDate> {
+        let months = span.get_years() as i32 * 12 + span.get_months();
+        let time = span.years(0).months(0).weeks(0).days(0);
+        // only time units are left, the conversion can't fail
+        let time_days = jiff::SignedDuration::try_from(time).ok()?.as_secs() / 86_400;
+        let days = span.get_weeks() as i64 * 7 + span.get_days() as i64 + time_days;
+        let date = self.add_months(months)?;
+        date.with_day_number(date.day_number + days).ok()
+    }
+
+    // the caller must make sure mcjdn is between MIN_MCJDN and MAX_MCJDN
+    const fn from_mcjdn(mcjdn: usize) -> HijriDate {
+        let (year, month, day, month_len) = mcjdn_to_hijri(mcjdn);
+        let day_number = DayNumber::from_jdn(mcjdn as i64 + MCJDN_EPOCH);
+        let (year_gr, month_gr, day_gr) = day_number.gregorian();
+
+        HijriDate {
+            //hijri
+            day,
+            month,
+            month_len,
+            year: year as i32,
+
+            //gregorian
+            day_gr: day_gr as usize,
+            month_gr: month_gr as usize,
+            year_gr,
+            day_number,
+            extended: false,
+        }
+    }
+
+    // the extended dates are in the chrono range
+    #[cfg(feature = "chrono")]
+    const fn date_gr(&self) -> NaiveDate {
+        match NaiveDate::from_ymd_opt(self.year_gr, self.month_gr as u32, self.day_gr as u32) {
+            Some(date_gr) => date_gr,
+            None => panic!("Wrong gegorean date foramt"),
+        }
     }
 }
 
-fn valid_hijri_date(year: usize, month: usize, day: usize) -> Result<(), String> {
-    if month > 12 {
+/// Returns the table index of the month
+#[cfg(feature = "std")]
+fn valid_hijri_date(year: i32, month: usize, day: usize) -> Result<usize, String> {
+    if !(1..=12).contains(&month) {
         bail!("enter a valid month, Err m = {}", month);
     }
-    if day > 30 {
+    if !(1..=30).contains(&day) {
         bail!("enter a valid day, Err d = {}", day);
     }
-    if year < 1357 {
-        bail!("minumum handled hijri year is 1357");
+    let index = match usize::try_from(year)
+        .ok()
+        .and_then(|y| month_index(y, month))
+    {
+        Some(index) => index,
+        None if year < HijriDate::MIN.year => bail!(
+            "minimum handled hijri date is {}, input date: {}-{}-{}",
+            HijriDate::MIN.format("%Y-%m-%d"),
+            year,
+            month,
+            day
+        ),
+        None => bail!(
+            "maximum handled hijri date is {}, input date: {}-{}-{}",
+            HijriDate::MAX.format("%Y-%m-%d"),
+            year,
+            month,
+            day
+        ),
+    };
+    if day > month_len(index) {
+        bail!(
+            "enter a valid day, {}-{} has {} days, Err d = {}",
+            year,
+            month,
+            month_len(index),
+            day
+        );
     }
-    if year > 1499 {
-        bail!("maximum handled hijri year is 1499");
+    Ok(index)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn julian_day_number(year: i32, month: usize, day: usize) -> Result<DayNumber, String> {
+    match DayNumber::from_julian(year, month as u32, day as u32) {
+        Some(day_number) => Ok(day_number),
+        None => bail!(
+            "Wrong julian date format, input date: {}-{}-{}",
+            year,
+            month,
+            day
+        ),
     }
-    Ok(())
 }
 
-fn valid_greorian_date(year_gr: usize, month_gr: usize, day_gr: usize) -> Result<(), String> {
-    if month_gr > 12 {
+#[cfg(feature = "std")]
+fn valid_greorian_date(year_gr: i32, month_gr: usize, day_gr: usize) -> Result<DayNumber, String> {
+    if !(1..=12).contains(&month_gr) {
         bail!("enter a valid month, Err m = {}", month_gr);
     }
-    if day_gr > 31 {
+    if !(1..=31).contains(&day_gr) {
         bail!("enter a valid day, Err d = {}", day_gr);
     }
-    if year_gr < 1938 {
+    let day_number = match DayNumber::from_gregorian(year_gr, month_gr as u32, day_gr as u32) {
+        Some(day_number) => day_number,
+        None => bail!("Wrong gegorean date foramt"),
+    };
+    if day_number < HijriDate::MIN.day_number {
         bail!(
-            "minumum handled gregorian year is 1938, input year: {}",
-            year_gr
+            "minimum handled gregorian date is {}-{:02}-{:02}, input date: {}-{:02}-{:02}",
+            HijriDate::MIN.year_gr,
+            HijriDate::MIN.month_gr,
+            HijriDate::MIN.day_gr,
+            year_gr,
+            month_gr,
+            day_gr
         );
     }
-    if year_gr > 2076 {
-        bail!("maximum handled gregorian year is 2076");
+    if day_number > HijriDate::MAX.day_number {
+        bail!(
+            "maximum handled gregorian date is {}-{:02}-{:02}, input date: {}-{:02}-{:02}",
+            HijriDate::MAX.year_gr,
+            HijriDate::MAX.month_gr,
+            HijriDate::MAX.day_gr,
+            year_gr,
+            month_gr,
+            day_gr
+        );
     }
-    Ok(())
+    Ok(day_number)
 }
@@ -0,0 +1,150 @@
+#[cfg(feature = "std")]
+use crate::umalqura::{hijri_to_mcjdn, month_index, month_len};
+use crate::CompactHijriDate;
+#[cfg(feature = "std")]
+use crate::HijriDate;
+
+#[cfg(feature = "std")]
+impl HijriDate {
+    /// get data from hijri date, `None` if the date is invalid or out of the handled range.
+    ///
+    /// Usable in constants, see also the [`hijri!`](crate::hijri) macro.
+    ///
+    /// ```rust
+    /// use hijri_date::HijriDate;
+    ///
+    /// const RAMADAN: HijriDate = match HijriDate::from_hijri_opt(1445, 9, 1) {
+    ///     Some(date) => date,
+    ///     None => panic!("invalid hijri date"),
+    /// };
+    /// assert_eq!(RAMADAN.to_jdn(), 2_460_381);
+    /// assert!(HijriDate::from_hijri_opt(1445, 9, 31).is_none());
+    /// ```
+    pub const fn from_hijri_opt(year: i32, month: usize, day: usize) -> Option<HijriDate> {
+        if year < 0 || month < 1 || month > 12 || day < 1 {
+            return None;
+        }
+        match month_index(year as usize, month) {
+            Some(index) if day <= month_len(index) => {
+                Some(HijriDate::from_mcjdn(hijri_to_mcjdn(index, day)))
+            }
+            _ => None,
+        }
+    }
+
+    // `year-month-day` written by the `hijri!` macro
+    #[doc(hidden)]
+    pub const fn from_literal(literal: &str) -> Option<HijriDate> {
+        match parse_literal(literal) {
+            Some((year, month, day)) => HijriDate::from_hijri_opt(year, month, day),
+            None => None,
+        }
+    }
+}
+
+impl CompactHijriDate {
+    // `year-month-day` written by the `compact_hijri!` macro
+    #[doc(hidden)]
+    pub const fn from_literal(literal: &str) -> Option<CompactHijriDate> {
+        match parse_literal(literal) {
+            Some((year, month, day)) => match CompactHijriDate::from_hijri(year, month, day) {
+                Ok(date) => Some(date),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+}
+
+// (year, month, day) of a `year-month-day` literal, the tokens may be separated by spaces
+const fn parse_literal(literal: &str) -> Option<(i32, usize, usize)> {
+    let bytes = literal.as_bytes();
+    let mut parts = [0usize; 3];
+    let (mut part, mut digits, mut i) = (0, 0, 0);
+    while i < bytes.len() {
+        match bytes[i] {
+            b'0'..=b'9' if digits < 5 => {
+                parts[part] = parts[part] * 10 + (bytes[i] - b'0') as usize;
+                digits += 1;
+            }
+            b'-' if digits > 0 && part < 2 => {
+                part += 1;
+                digits = 0;
+            }
+            b' ' => {}
+            _ => return None,
+        }
+        i += 1;
+    }
+    if part != 2 || digits == 0 {
+        return None;
+    }
+    Some((parts[0] as i32, parts[1], parts[2]))
+}
+
+/// Hijri date checked at compile time, written `year-month-day`.
+///
+/// ```rust
+/// use hijri_date::{hijri, HijriDate};
+///
+/// let hd = hijri!(1445-09-01);
+/// assert_eq!(hd, HijriDate::from_hijri(1445, 9, 1).unwrap());
+/// ```
+///
+/// Invalid or out of range dates don't compile:
+///
+/// ```compile_fail
+/// let hd = hijri_date::hijri!(1445-13-01);
+/// ```
+///
+/// ```compile_fail
+/// let hd = hijri_date::hijri!(1501-01-01);
+/// ```
+///
+/// ```compile_fail
+/// let hd = hijri_date::hijri!(1445/09/01);
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! hijri {
+    ($($date:tt)+) => {{
+        const DATE: $crate::HijriDate = match $crate::HijriDate::from_literal(stringify!($($date)+)) {
+            Some(date) => date,
+            None => panic!(concat!(
+                "invalid or out of range hijri date ",
+                stringify!($($date)+)
+            )),
+        };
+        DATE
+    }};
+}
+
+/// [`CompactHijriDate`] checked at compile time, written `year-month-day`. Unlike
+/// [`hijri!`](crate::hijri) it is available without the `std` feature.
+///
+/// ```rust
+/// use hijri_date::{compact_hijri, CompactHijriDate};
+///
+/// const RAMADAN: CompactHijriDate = compact_hijri!(1445-09-01);
+/// assert_eq!(RAMADAN.to_gregorian(), (2024, 3, 11));
+/// ```
+///
+/// Invalid or out of range dates don't compile:
+///
+/// ```compile_fail
+/// let hd = hijri_date::compact_hijri!(1445-08-30);
+/// ```
+#[macro_export]
+macro_rules! compact_hijri {
+    ($($date:tt)+) => {{
+        const DATE: $crate::CompactHijriDate =
+            match $crate::CompactHijriDate::from_literal(stringify!($($date)+)) {
+                Some(date) => date,
+                None => panic!(concat!(
+                    "invalid or out of range hijri date ",
+                    stringify!($($date)+)
+                )),
+            };
+        DATE
+    }};
+}
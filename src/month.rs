@@ -0,0 +1,202 @@
+use crate::Locale;
+#[cfg(feature = "std")]
+use crate::{HijriDate, NumberingSystem};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+/// Month of the hijri year.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriMonth};
+///
+/// let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+/// assert_eq!(hd.month_enum(), HijriMonth::Ramadan);
+/// assert_eq!("Shawwal".parse(), Ok(HijriMonth::Ramadan.succ()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HijriMonth {
+    Muharram,
+    Safar,
+    RabiAlAwwal,
+    RabiAlThani,
+    JumadaAlUla,
+    JumadaAlAkhirah,
+    Rajab,
+    Shaban,
+    Ramadan,
+    Shawwal,
+    DhulQadah,
+    DhulHijjah,
+}
+
+use HijriMonth::*;
+
+const MONTHS: [HijriMonth; 12] = [
+    Muharram,
+    Safar,
+    RabiAlAwwal,
+    RabiAlThani,
+    JumadaAlUla,
+    JumadaAlAkhirah,
+    Rajab,
+    Shaban,
+    Ramadan,
+    Shawwal,
+    DhulQadah,
+    DhulHijjah,
+];
+
+// (arabic, english, variant name) for every month, in order
+const NAMES: [(&str, &str, &str); 12] = [
+    ("محرم", "Muharram", "Muharram"),
+    ("صفر", "Safar", "Safar"),
+    ("ربيع الأول", "Rabi' al-Awwal", "RabiAlAwwal"),
+    ("ربيع الثاني", "Rabi' al-Thani", "RabiAlThani"),
+    ("جمادي الأولى", "Jumada al-Ula", "JumadaAlUla"),
+    ("جمادي الآخرة", "Jumada al-Akhirah", "JumadaAlAkhirah"),
+    ("رجب", "Rajab", "Rajab"),
+    ("شعبان", "Sha'ban", "Shaban"),
+    ("رمضان", "Ramadan", "Ramadan"),
+    ("شوال", "Shawwal", "Shawwal"),
+    ("ذو القعدة", "Dhu al-Qi'dah", "DhulQadah"),
+    ("ذو الحجة", "Dhu al-Hijjah", "DhulHijjah"),
+];
+
+impl HijriMonth {
+    /// Month number, starting from 1 for Muharram
+    pub fn number(self) -> usize {
+        self as usize + 1
+    }
+
+    /// Month from its number (1 to 12)
+    pub fn from_number(n: usize) -> Option<HijriMonth> {
+        MONTHS.get(n.checked_sub(1)?).copied()
+    }
+
+    /// Next month, Muharram follows Dhu al-Hijjah
+    pub fn succ(self) -> HijriMonth {
+        MONTHS[(self as usize + 1) % 12]
+    }
+
+    /// Previous month, Dhu al-Hijjah precedes Muharram
+    pub fn pred(self) -> HijriMonth {
+        MONTHS[(self as usize + 11) % 12]
+    }
+
+    /// Name of the month in the given locale
+    pub fn name(self, locale: Locale) -> &'static str {
+        let (ar, en, _) = NAMES[self as usize];
+        match locale {
+            Locale::Arabic => ar,
+            Locale::English => en,
+        }
+    }
+}
+
+/// Parses the month number, its arabic name or its english name (case, spaces and
+/// punctuation are ignored).
+#[cfg(feature = "std")]
+impl FromStr for HijriMonth {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(n) = NumberingSystem::to_latin(s).parse() {
+            return HijriMonth::from_number(n).ok_or(format!("enter a valid month, Err m = {}", n));
+        }
+
+        let key = normalize(s);
+        NAMES
+            .iter()
+            .position(|(ar, en, variant)| {
+                key == normalize(ar) || key == normalize(en) || key == normalize(variant)
+            })
+            .map(|i| MONTHS[i])
+            .ok_or(format!("unknown hijri month: {}", s))
+    }
+}
+
+/// Month of a given hijri year, usable as a map key.
+///
+/// Ordered chronologically, displayed as `1445-09`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HijriYearMonth {
+    pub year: i32,
+    pub month: HijriMonth,
+}
+
+impl HijriYearMonth {
+    pub fn new(year: i32, month: HijriMonth) -> Self {
+        Self { year, month }
+    }
+
+    /// Next month, Muharram of the next year follows Dhu al-Hijjah
+    pub fn succ(self) -> Self {
+        let year = if self.month == DhulHijjah {
+            self.year + 1
+        } else {
+            self.year
+        };
+        Self::new(year, self.month.succ())
+    }
+
+    /// Previous month, Dhu al-Hijjah of the previous year precedes Muharram
+    pub fn pred(self) -> Self {
+        let year = if self.month == Muharram {
+            self.year - 1
+        } else {
+            self.year
+        };
+        Self::new(year, self.month.pred())
+    }
+
+    /// First day of the month, an error if the month isn't handled
+    #[cfg(feature = "std")]
+    pub fn first_day(self) -> Result<HijriDate, String> {
+        HijriDate::from_hijri(self.year, self.month.number(), 1)
+    }
+}
+
+impl fmt::Display for HijriYearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}", self.year, self.month.number())
+    }
+}
+
+#[cfg(feature = "std")]
+impl HijriDate {
+    pub fn year_month(&self) -> HijriYearMonth {
+        HijriYearMonth::new(self.year(), self.month_enum())
+    }
+}
+
+// english names of the gregorian months, in order
+pub(crate) const GREGORIAN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Lowercase without spaces and punctuation, with the arabic alef and ya variants unified
+#[cfg(feature = "std")]
+pub(crate) fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| match c {
+            'أ' | 'إ' | 'آ' => 'ا',
+            'ى' => 'ي',
+            c => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,106 @@
+use crate::{HijriDate, HijriWeekday};
+
+/// Snapping to the start or end of a month or year, and finding weekdays.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriWeekday};
+///
+/// let hd = HijriDate::from_hijri(1445, 9, 14).unwrap();
+/// assert_eq!(hd.first_day_of_month(), HijriDate::from_hijri(1445, 9, 1).unwrap());
+/// assert_eq!(hd.last_day_of_month(), HijriDate::from_hijri(1445, 9, 30).unwrap());
+///
+/// // last Friday of Ramadan
+/// let friday = hd.nth_weekday_of_month(-1, HijriWeekday::Friday).unwrap();
+/// assert_eq!(friday, HijriDate::from_hijri(1445, 9, 26).unwrap());
+///
+/// assert_eq!(hd.ordinal(), 250);
+/// assert_eq!(hd.year_len(), 354);
+/// ```
+impl HijriDate {
+    pub fn first_day_of_month(&self) -> HijriDate {
+        self.same_kind(self.year, self.month, 1)
+    }
+
+    pub fn last_day_of_month(&self) -> HijriDate {
+        self.same_kind(self.year, self.month, self.month_len)
+    }
+
+    pub fn first_day_of_year(&self) -> HijriDate {
+        self.same_kind(self.year, 1, 1)
+    }
+
+    pub fn last_day_of_year(&self) -> HijriDate {
+        let month_len = self.same_kind(self.year, 12, 1).month_len;
+        self.same_kind(self.year, 12, month_len)
+    }
+
+    /// Day of the year, starting from 1 for 1 Muharram
+    pub fn ordinal(&self) -> usize {
+        (self.day_number - self.first_day_of_year().day_number) as usize + 1
+    }
+
+    /// get data from a hijri year and day of the year (starting from 1).
+    pub fn from_ordinal(year: i32, ordinal: usize) -> Result<HijriDate, String> {
+        let first = HijriDate::from_hijri(year, 1, 1)?;
+        if !(1..=first.year_len()).contains(&ordinal) {
+            bail!(
+                "enter a valid day of the year, {} has {} days, Err o = {}",
+                year,
+                first.year_len(),
+                ordinal
+            );
+        }
+        HijriDate::from_day_number(first.day_number + (ordinal - 1) as i64)
+    }
+
+    /// Number of days in the hijri year, 354 or 355
+    pub fn year_len(&self) -> usize {
+        self.last_day_of_year().ordinal()
+    }
+
+    /// Whether the hijri year has 355 days
+    pub fn is_long_year(&self) -> bool {
+        self.year_len() == 355
+    }
+
+    /// First `weekday` strictly after this date, `None` past the last handled day
+    pub fn next_weekday(&self, weekday: HijriWeekday) -> Option<HijriDate> {
+        let days = (weekday.number() + 6 - self.weekday().number()) % 7 + 1;
+        self.with_day_number(self.day_number + days as i64).ok()
+    }
+
+    /// Last `weekday` strictly before this date, `None` before the first handled day
+    pub fn prev_weekday(&self, weekday: HijriWeekday) -> Option<HijriDate> {
+        let days = (self.weekday().number() + 6 - weekday.number()) % 7 + 1;
+        self.with_day_number(self.day_number - days as i64).ok()
+    }
+
+    /// `n`th `weekday` of the month of this date, counted from the end of the month when `n`
+    /// is negative (`-1` being the last one). `None` if there is no such day.
+    pub fn nth_weekday_of_month(&self, n: i32, weekday: HijriWeekday) -> Option<HijriDate> {
+        // no month has more than 5 of a weekday
+        if n == 0 || !(-5..=5).contains(&n) {
+            return None;
+        }
+        let day = if n > 0 {
+            let first = self.first_day_of_month().weekday();
+            let offset = (weekday.number() + 7 - first.number()) % 7;
+            1 + offset as i32 + 7 * (n - 1)
+        } else {
+            let last = self.last_day_of_month().weekday();
+            let offset = (last.number() + 7 - weekday.number()) % 7;
+            self.month_len as i32 - offset as i32 - 7 * (-n - 1)
+        };
+
+        if day < 1 || day > self.month_len as i32 {
+            return None;
+        }
+        Some(self.same_kind(self.year, self.month, day as usize))
+    }
+
+    // every month of the year of a date is handled, the day must be within the month
+    fn same_kind(&self, year: i32, month: usize, day: usize) -> HijriDate {
+        // shouldn't fail
+        self.with_hijri(year, month, day).unwrap()
+    }
+}
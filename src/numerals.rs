@@ -0,0 +1,66 @@
+/// Digits used to render numbers.
+///
+/// Parsing accepts the digits of every system, formatting uses the selected one.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, NumberingSystem};
+///
+/// let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+/// assert_eq!(hd.format_with("%d %M %Y", NumberingSystem::ArabicIndic), "١ رمضان ١٤٤٥");
+/// assert_eq!("١٤٤٥/٩/١".parse(), Ok(hd.clone()));
+/// assert_eq!("۱۴۴۵-۰۹-۰۱".parse(), Ok(hd));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberingSystem {
+    /// 0123456789
+    Latin,
+    /// ٠١٢٣٤٥٦٧٨٩, used in most of the Arab world
+    ArabicIndic,
+    /// ۰۱۲۳۴۵۶۷۸۹, used in Iran, Afghanistan and Pakistan
+    ExtendedArabicIndic,
+}
+
+impl NumberingSystem {
+    /// Digits from 0 to 9
+    pub fn digits(self) -> [char; 10] {
+        match self {
+            NumberingSystem::Latin => ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+            NumberingSystem::ArabicIndic => ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+            NumberingSystem::ExtendedArabicIndic => {
+                ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹']
+            }
+        }
+    }
+
+    /// Replaces the latin digits of `s` by the digits of this system
+    pub fn apply(self, s: &str) -> String {
+        let digits = self.digits();
+        s.chars()
+            .map(|c| match c.to_digit(10) {
+                Some(d) => digits[d as usize],
+                _ => c,
+            })
+            .collect()
+    }
+
+    /// Replaces the digits of any system in `s` by latin digits
+    pub fn to_latin(s: &str) -> String {
+        s.chars()
+            .map(|c| match digit_value(c) {
+                Some(d) => NumberingSystem::Latin.digits()[d],
+                None => c,
+            })
+            .collect()
+    }
+}
+
+// value of a digit of any system
+fn digit_value(c: char) -> Option<usize> {
+    [
+        NumberingSystem::Latin,
+        NumberingSystem::ArabicIndic,
+        NumberingSystem::ExtendedArabicIndic,
+    ]
+    .iter()
+    .find_map(|system| system.digits().iter().position(|&d| d == c))
+}
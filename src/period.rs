@@ -0,0 +1,113 @@
+use crate::HijriDate;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Neg;
+
+/// Difference between two hijri dates in years, months and days.
+///
+/// All the fields have the same sign, they are negative when going back in time.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriPeriod};
+///
+/// let birth = HijriDate::from_hijri(1420, 6, 15).unwrap();
+/// let today = HijriDate::from_hijri(1445, 9, 1).unwrap();
+/// assert_eq!(birth.until(&today), HijriPeriod::new(25, 2, 15));
+/// assert_eq!(today.since(&birth), HijriPeriod::new(25, 2, 15));
+/// assert_eq!(today.until(&birth), HijriPeriod::new(-25, -2, -15));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HijriPeriod {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl HijriPeriod {
+    pub fn new(years: i32, months: i32, days: i32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.years < 0 || self.months < 0 || self.days < 0
+    }
+}
+
+impl Neg for HijriPeriod {
+    type Output = HijriPeriod;
+
+    fn neg(self) -> HijriPeriod {
+        HijriPeriod::new(-self.years, -self.months, -self.days)
+    }
+}
+
+/// ISO 8601 representation, example `P3Y2M11D`
+impl fmt::Display for HijriPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        write!(
+            f,
+            "P{}Y{}M{}D",
+            self.years.abs(),
+            self.months.abs(),
+            self.days.abs()
+        )
+    }
+}
+
+impl HijriDate {
+    /// Calendar-aware difference from this date to `other`.
+    ///
+    /// Whole months are counted first, adding a month to the 30th of a month keeps the day
+    /// clamped to the length of the next month, then the remaining days are counted.
+    pub fn until(&self, other: &HijriDate) -> HijriPeriod {
+        if other < self {
+            return -other.until(self);
+        }
+
+        let months = (other.year as i64 * 12 + other.month as i64)
+            - (self.year as i64 * 12 + self.month as i64);
+        // the handled years are far from the i32 limits, so are the months between them
+        let mut months = i32::try_from(months).unwrap();
+        // both dates are handled so every month before the one of `other` is too, in the
+        // extended kind if one of them is extended
+        let base = if other.extended {
+            self.clone().extended()
+        } else {
+            self.clone()
+        };
+        // the day in the month of `other` may be past the handled range, so after `other`
+        let start = match base.add_months(months) {
+            Some(start) if start <= *other => start,
+            _ => {
+                months -= 1;
+                base.add_months(months).unwrap()
+            }
+        };
+        let days = (other.day_number - start.day_number) as i32;
+
+        HijriPeriod::new(months / 12, months % 12, days)
+    }
+
+    /// Calendar-aware difference from `other` to this date, see [`HijriDate::until`]
+    pub fn since(&self, other: &HijriDate) -> HijriPeriod {
+        other.until(self)
+    }
+
+    /// Number of whole hijri months from this date to `other`, rounded toward zero
+    pub fn months_between(&self, other: &HijriDate) -> i32 {
+        let period = self.until(other);
+        period.years * 12 + period.months
+    }
+
+    /// Number of whole hijri years from this date to `other`, rounded toward zero
+    pub fn years_between(&self, other: &HijriDate) -> i32 {
+        self.until(other).years
+    }
+}
@@ -0,0 +1,405 @@
+//! Prayer times calculation.
+//!
+//! The astronomical formulas are the ones used by [PrayTimes.org](http://praytimes.org/calculation),
+//! they are accurate to about a minute for the years handled by this crate.
+//!
+//! ```rust
+//! use hijri_date::HijriDate;
+//! use hijri_date::prayer_times::{Location, Params};
+//!
+//! let mecca = Location::new(21.4225, 39.8262, 3.0);
+//! let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+//! let times = hd.prayer_times(&mecca, &Params::default()).unwrap();
+//! println!("{}", times.fajr.format("%H:%M"));
+//! ```
+
+use crate::{HijriDate, HijriMonth};
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+/// Angle of the sun below the horizon at sunrise and sunset (refraction + sun radius).
+const RISE_SET_ANGLE: f64 = 0.833;
+
+/// Position on earth where the prayer times are calculated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    /// Latitude in degrees, positive north of the equator
+    pub latitude: f64,
+    /// Longitude in degrees, positive east of Greenwich
+    pub longitude: f64,
+    /// Offset from UTC in hours used for the resulting times, example `3.0` for Mecca
+    pub utc_offset: f64,
+}
+
+impl Location {
+    pub fn new(latitude: f64, longitude: f64, utc_offset: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            utc_offset,
+        }
+    }
+}
+
+/// Calculation method, defines the sun angles used for fajr, maghrib and isha.
+///
+/// ```text
+///     method              fajr     isha
+///
+///     UmmAlQura           18.5°    90 min after maghrib (120 min in Ramadan)
+///     MuslimWorldLeague   18°      17°
+///     Isna                15°      15°
+///     Egyptian            19.5°    17.5°
+///     Karachi             18°      18°
+///     Tehran              17.7°    14°   (maghrib at 4.5°)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    UmmAlQura,
+    MuslimWorldLeague,
+    Isna,
+    Egyptian,
+    Karachi,
+    Tehran,
+}
+
+enum Isha {
+    Angle(f64),
+    Minutes(f64),
+}
+
+impl Method {
+    fn fajr_angle(self) -> f64 {
+        match self {
+            Method::UmmAlQura => 18.5,
+            Method::MuslimWorldLeague => 18.0,
+            Method::Isna => 15.0,
+            Method::Egyptian => 19.5,
+            Method::Karachi => 18.0,
+            Method::Tehran => 17.7,
+        }
+    }
+
+    fn isha(self, ramadan: bool) -> Isha {
+        match self {
+            Method::UmmAlQura if ramadan => Isha::Minutes(120.0),
+            Method::UmmAlQura => Isha::Minutes(90.0),
+            Method::MuslimWorldLeague => Isha::Angle(17.0),
+            Method::Isna => Isha::Angle(15.0),
+            Method::Egyptian => Isha::Angle(17.5),
+            Method::Karachi => Isha::Angle(18.0),
+            Method::Tehran => Isha::Angle(14.0),
+        }
+    }
+
+    fn maghrib_angle(self) -> Option<f64> {
+        match self {
+            Method::Tehran => Some(4.5),
+            _ => None,
+        }
+    }
+}
+
+/// Juristic method used for asr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Asr {
+    /// Shadow length equals the object length (Shafi'i, Maliki, Hanbali)
+    Shafii,
+    /// Shadow length equals twice the object length
+    Hanafi,
+}
+
+impl Asr {
+    fn shadow_factor(self) -> f64 {
+        match self {
+            Asr::Shafii => 1.0,
+            Asr::Hanafi => 2.0,
+        }
+    }
+}
+
+/// Adjustment of fajr and isha for locations where the sun doesn't go deep enough below the
+/// horizon during the night.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighLatitudeRule {
+    /// No adjustment, calculation fails if fajr or isha can't be determined
+    None,
+    /// Fajr and isha are at most half of the night away from sunrise and sunset
+    MiddleOfTheNight,
+    /// Fajr and isha are at most a seventh of the night away from sunrise and sunset
+    OneSeventh,
+    /// Fajr and isha are at most `angle / 60` of the night away from sunrise and sunset
+    AngleBased,
+}
+
+/// Parameters of the calculation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub method: Method,
+    pub asr: Asr,
+    pub high_latitude: HighLatitudeRule,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            method: Method::UmmAlQura,
+            asr: Asr::Shafii,
+            high_latitude: HighLatitudeRule::MiddleOfTheNight,
+        }
+    }
+}
+
+impl Params {
+    pub fn new(method: Method) -> Self {
+        Self {
+            method,
+            ..Self::default()
+        }
+    }
+}
+
+/// Prayer times of one day, in the local time of the [`Location`] (rounded to the minute).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrayerTimes {
+    pub fajr: NaiveTime,
+    pub sunrise: NaiveTime,
+    pub dhuhr: NaiveTime,
+    pub asr: NaiveTime,
+    pub maghrib: NaiveTime,
+    pub isha: NaiveTime,
+}
+
+impl PrayerTimes {
+    /// Prayer times of the given gregorian date.
+    pub fn from_gr(
+        year_gr: i32,
+        month_gr: usize,
+        day_gr: usize,
+        location: &Location,
+        params: &Params,
+    ) -> Result<PrayerTimes, String> {
+        let date = match NaiveDate::from_ymd_opt(year_gr, month_gr as u32, day_gr as u32) {
+            Some(date) => date,
+            None => bail!("Wrong gegorean date foramt"),
+        };
+        // the ramadan adjustment is skipped for dates outside the handled range
+        let ramadan = HijriDate::from_gr(year_gr, month_gr, day_gr)
+            .map(|hd| hd.month_enum() == HijriMonth::Ramadan)
+            .unwrap_or(false);
+
+        compute(date, location, params, ramadan)
+    }
+
+    /// Prayer times of the given hijri date.
+    pub fn from_hijri(
+        date: &HijriDate,
+        location: &Location,
+        params: &Params,
+    ) -> Result<PrayerTimes, String> {
+        compute(
+            date.date_gr(),
+            location,
+            params,
+            date.month_enum() == HijriMonth::Ramadan,
+        )
+    }
+}
+
+impl HijriDate {
+    /// Prayer times of this day at the given location, see [`prayer_times`](crate::prayer_times).
+    pub fn prayer_times(
+        &self,
+        location: &Location,
+        params: &Params,
+    ) -> Result<PrayerTimes, String> {
+        PrayerTimes::from_hijri(self, location, params)
+    }
+}
+
+fn compute(
+    date: NaiveDate,
+    location: &Location,
+    params: &Params,
+    ramadan: bool,
+) -> Result<PrayerTimes, String> {
+    let sun = Sun {
+        jd: julian_date(date.year(), date.month(), date.day()) - location.longitude / (15.0 * 24.0),
+        latitude: location.latitude,
+    };
+    let method = params.method;
+
+    // first approximation of each time (in hours) used to find the sun position
+    let fajr = sun.angle_time(method.fajr_angle(), 5.0, true);
+    let sunrise = sun.angle_time(RISE_SET_ANGLE, 6.0, true);
+    let dhuhr = sun.mid_day(12.0);
+    let asr = sun.asr_time(params.asr.shadow_factor(), 13.0);
+    let sunset = sun.angle_time(RISE_SET_ANGLE, 18.0, false);
+    let maghrib = match method.maghrib_angle() {
+        Some(angle) => sun.angle_time(angle, 18.0, false),
+        None => sunset,
+    };
+    let isha = match method.isha(ramadan) {
+        Isha::Angle(angle) => sun.angle_time(angle, 18.0, false),
+        Isha::Minutes(minutes) => maghrib + minutes / 60.0,
+    };
+
+    if sunrise.is_nan() || sunset.is_nan() {
+        bail!(
+            "the sun doesn't rise or set at latitude {}",
+            location.latitude
+        );
+    }
+
+    // convert from local solar time to the requested utc offset
+    let shift = location.utc_offset - location.longitude / 15.0;
+    let (mut fajr, sunrise, dhuhr, asr, sunset, maghrib, mut isha) = (
+        fajr + shift,
+        sunrise + shift,
+        dhuhr + shift,
+        asr + shift,
+        sunset + shift,
+        maghrib + shift,
+        isha + shift,
+    );
+
+    let night = fix_hour(sunrise - sunset);
+    let portion = |angle: f64| match params.high_latitude {
+        HighLatitudeRule::None => None,
+        HighLatitudeRule::MiddleOfTheNight => Some(night / 2.0),
+        HighLatitudeRule::OneSeventh => Some(night / 7.0),
+        HighLatitudeRule::AngleBased => Some(night * angle / 60.0),
+    };
+    if let Some(portion) = portion(method.fajr_angle()) {
+        if fajr.is_nan() || fix_hour(sunrise - fajr) > portion {
+            fajr = sunrise - portion;
+        }
+    }
+    if let Isha::Angle(angle) = method.isha(ramadan) {
+        if let Some(portion) = portion(angle) {
+            if isha.is_nan() || fix_hour(isha - sunset) > portion {
+                isha = sunset + portion;
+            }
+        }
+    }
+
+    if fajr.is_nan() || isha.is_nan() || asr.is_nan() {
+        bail!(
+            "fajr or isha can't be determined at latitude {} without a high latitude rule",
+            location.latitude
+        );
+    }
+
+    Ok(PrayerTimes {
+        fajr: to_time(fajr),
+        sunrise: to_time(sunrise),
+        dhuhr: to_time(dhuhr),
+        asr: to_time(asr),
+        maghrib: to_time(maghrib),
+        isha: to_time(isha),
+    })
+}
+
+struct Sun {
+    jd: f64,
+    latitude: f64,
+}
+
+impl Sun {
+    /// Returns (declination, equation of time) at the given local hour
+    fn position(&self, hour: f64) -> (f64, f64) {
+        let d = self.jd + hour / 24.0 - 2_451_545.0;
+        let g = fix_angle(357.529 + 0.985_600_28 * d);
+        let q = fix_angle(280.459 + 0.985_647_36 * d);
+        let l = fix_angle(q + 1.915 * dsin(g) + 0.020 * dsin(2.0 * g));
+        let e = 23.439 - 0.000_000_36 * d;
+
+        let ra = fix_hour(datan2(dcos(e) * dsin(l), dcos(l)) / 15.0);
+        let eqt = q / 15.0 - ra;
+        let decl = dasin(dsin(e) * dsin(l));
+
+        (decl, eqt)
+    }
+
+    fn mid_day(&self, hour: f64) -> f64 {
+        let (_, eqt) = self.position(hour);
+        fix_hour(12.0 - eqt)
+    }
+
+    /// Time at which the sun reaches `angle` degrees below the horizon, before noon if `ccw`
+    fn angle_time(&self, angle: f64, hour: f64, ccw: bool) -> f64 {
+        let (decl, _) = self.position(hour);
+        let noon = self.mid_day(hour);
+        let t = dacos(
+            (-dsin(angle) - dsin(decl) * dsin(self.latitude)) / (dcos(decl) * dcos(self.latitude)),
+        ) / 15.0;
+
+        if ccw {
+            noon - t
+        } else {
+            noon + t
+        }
+    }
+
+    fn asr_time(&self, factor: f64, hour: f64) -> f64 {
+        let (decl, _) = self.position(hour);
+        let angle = -datan(1.0 / (factor + dtan((self.latitude - decl).abs())));
+        self.angle_time(angle, hour, false)
+    }
+}
+
+fn julian_date(mut year: i32, mut month: u32, day: u32) -> f64 {
+    if month <= 2 {
+        year -= 1;
+        month += 12;
+    }
+    let a = (f64::from(year) / 100.0).floor();
+    let b = 2.0 - a + (a / 4.0).floor();
+
+    (365.25 * (f64::from(year) + 4716.0)).floor()
+        + (30.6001 * (f64::from(month) + 1.0)).floor()
+        + f64::from(day)
+        + b
+        - 1524.5
+}
+
+fn to_time(hour: f64) -> NaiveTime {
+    let minutes = (fix_hour(hour) * 60.0).round() as u32 % (24 * 60);
+    // shouldn't fail
+    NaiveTime::from_hms_opt(minutes / 60, minutes % 60, 0).unwrap()
+}
+
+fn fix_angle(a: f64) -> f64 {
+    a.rem_euclid(360.0)
+}
+
+fn fix_hour(h: f64) -> f64 {
+    h.rem_euclid(24.0)
+}
+
+fn dsin(d: f64) -> f64 {
+    d.to_radians().sin()
+}
+
+fn dcos(d: f64) -> f64 {
+    d.to_radians().cos()
+}
+
+fn dtan(d: f64) -> f64 {
+    d.to_radians().tan()
+}
+
+fn dasin(x: f64) -> f64 {
+    x.asin().to_degrees()
+}
+
+fn dacos(x: f64) -> f64 {
+    x.acos().to_degrees()
+}
+
+fn datan(x: f64) -> f64 {
+    x.atan().to_degrees()
+}
+
+fn datan2(y: f64, x: f64) -> f64 {
+    y.atan2(x).to_degrees()
+}
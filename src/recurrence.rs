@@ -0,0 +1,296 @@
+//! Recurring events expressed in the hijri calendar, similar to the iCalendar RRULE.
+//!
+//! ```rust
+//! use hijri_date::HijriDate;
+//! use hijri_date::HijriMonth::{Muharram, Shaban};
+//! use hijri_date::HijriWeekday::{Monday, Thursday};
+//! use hijri_date::recurrence::Recurrence;
+//!
+//! let start = HijriDate::from_hijri(1445, 1, 1).unwrap();
+//!
+//! // every 10 Muharram
+//! let ashura = Recurrence::yearly().by_month(&[Muharram]).by_day(&[10]);
+//! let next: Vec<_> = ashura.occurrences(&start).take(3).map(|d| d.year()).collect();
+//! assert_eq!(next, [1445, 1446, 1447]);
+//!
+//! // every Monday and Thursday of Sha'ban 1445
+//! let fasting = Recurrence::yearly()
+//!     .by_month(&[Shaban])
+//!     .by_weekday(&[Monday, Thursday])
+//!     .until(HijriDate::from_hijri(1445, 12, 29).unwrap());
+//! assert_eq!(fasting.occurrences(&start).count(), 8);
+//! ```
+
+use crate::{DayNumber, HijriDate, HijriMonth, HijriWeekday};
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+/// How often the recurrence repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Weekly,
+}
+
+/// What to do with a day that doesn't exist in a month, example the 30th of a 29 days month.
+///
+/// The missing negative days, example `-30` of a 29 days month, fall before the month and are
+/// handled in the other direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingDay {
+    /// No occurrence in that month
+    Skip,
+    /// Occurs on the last day of the month instead, the first day for negative days
+    LastDay,
+    /// Occurs on the first day of the next month instead, the last day of the previous month
+    /// for negative days
+    NextMonth,
+}
+
+/// Recurrence rule.
+///
+/// Without `by_*` filters the rule repeats the day, month and weekday of the start date.
+/// `by_day` accepts negative days counted from the end of the month, `-1` being the last day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    frequency: Frequency,
+    interval: usize,
+    by_month: Vec<HijriMonth>,
+    by_day: Vec<i32>,
+    by_weekday: Vec<HijriWeekday>,
+    count: Option<usize>,
+    until: Option<HijriDate>,
+    missing_day: MissingDay,
+}
+
+impl Recurrence {
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_month: Vec::new(),
+            by_day: Vec::new(),
+            by_weekday: Vec::new(),
+            count: None,
+            until: None,
+            missing_day: MissingDay::Skip,
+        }
+    }
+
+    pub fn yearly() -> Self {
+        Self::new(Frequency::Yearly)
+    }
+
+    pub fn monthly() -> Self {
+        Self::new(Frequency::Monthly)
+    }
+
+    pub fn weekly() -> Self {
+        Self::new(Frequency::Weekly)
+    }
+
+    /// Repeat every `interval` years, months or weeks
+    pub fn interval(mut self, interval: usize) -> Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    pub fn by_month(mut self, months: &[HijriMonth]) -> Self {
+        self.by_month = months.to_vec();
+        self
+    }
+
+    pub fn by_day(mut self, days: &[i32]) -> Self {
+        self.by_day = days.to_vec();
+        self
+    }
+
+    pub fn by_weekday(mut self, weekdays: &[HijriWeekday]) -> Self {
+        self.by_weekday = weekdays.to_vec();
+        self
+    }
+
+    /// Stop after `count` occurrences
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Stop after the given date (included)
+    pub fn until(mut self, until: HijriDate) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn missing_day(mut self, missing_day: MissingDay) -> Self {
+        self.missing_day = missing_day;
+        self
+    }
+
+    /// Occurrences on or after `start`, in order. The iteration ends at the last day handled
+    /// by the algorithm.
+    pub fn occurrences<'a>(&'a self, start: &HijriDate) -> Occurrences<'a> {
+        Occurrences {
+            rule: self,
+            start: start.clone(),
+            period: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+            done: false,
+        }
+    }
+
+    /// Candidates of the nth period, `None` once past the handled range
+    fn period(&self, start: &HijriDate, n: usize) -> Option<Vec<HijriDate>> {
+        let n = n.checked_mul(self.interval)?;
+        let n = i64::try_from(n).ok()?;
+        let mut dates = Vec::new();
+
+        match self.frequency {
+            Frequency::Yearly => {
+                let year = (start.year() as i64).checked_add(n)?;
+                if year > HijriDate::MAX.year() as i64 {
+                    return None;
+                }
+                let year = year as i32;
+                let months = if !self.by_month.is_empty() {
+                    self.by_month.clone()
+                } else if !self.by_day.is_empty() || !self.by_weekday.is_empty() {
+                    (1..=12).filter_map(HijriMonth::from_number).collect()
+                } else {
+                    vec![start.month_enum()]
+                };
+                for month in months {
+                    self.month_days(start, year, month.number(), &mut dates);
+                }
+            }
+            Frequency::Monthly => {
+                let total = (start.year() as i64 * 12 + start.month() as i64 - 1).checked_add(n)?;
+                let (year, month) = (total.div_euclid(12), total.rem_euclid(12) as usize + 1);
+                if year > HijriDate::MAX.year() as i64 {
+                    return None;
+                }
+                let year = year as i32;
+                if self.by_month.is_empty() || self.by_month.iter().any(|m| m.number() == month) {
+                    self.month_days(start, year, month, &mut dates);
+                }
+            }
+            Frequency::Weekly => {
+                let first = n.checked_mul(7)?.checked_add(start.to_jdn())?;
+                let first = DayNumber::from_jdn(first);
+                if first > HijriDate::MAX.day_number() {
+                    return None;
+                }
+                let weekdays = if self.by_weekday.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_weekday.clone()
+                };
+                let days = (0..7).filter_map(|i| HijriDate::from_day_number(first + i).ok());
+                dates.extend(days.filter(|date| {
+                    weekdays.contains(&date.weekday())
+                        && (self.by_month.is_empty() || self.by_month.contains(&date.month_enum()))
+                }));
+            }
+        }
+
+        dates.sort_by_key(HijriDate::day_number);
+        dates.dedup();
+        Some(dates)
+    }
+
+    fn month_days(&self, start: &HijriDate, year: i32, month: usize, dates: &mut Vec<HijriDate>) {
+        let month_len = match HijriDate::from_hijri(year, month, 1) {
+            Ok(first) => first.month_len(),
+            Err(_) => return,
+        };
+        let date = |day| HijriDate::from_hijri(year, month, day).ok();
+
+        if !self.by_day.is_empty() || self.by_weekday.is_empty() {
+            let days = if self.by_day.is_empty() {
+                vec![start.day() as i32]
+            } else {
+                self.by_day.clone()
+            };
+            for day in days {
+                if day == 0 || !(-30..=30).contains(&day) {
+                    continue;
+                }
+                let found = if day.unsigned_abs() as usize <= month_len {
+                    let day = if day < 0 {
+                        month_len as i32 + 1 + day
+                    } else {
+                        day
+                    };
+                    date(day as usize)
+                } else {
+                    match (self.missing_day, day < 0) {
+                        (MissingDay::Skip, _) => None,
+                        (MissingDay::LastDay, false) => date(month_len),
+                        (MissingDay::LastDay, true) => date(1),
+                        (MissingDay::NextMonth, false) => date(month_len).and_then(|last| {
+                            HijriDate::from_day_number(last.day_number() + 1).ok()
+                        }),
+                        (MissingDay::NextMonth, true) => date(1).and_then(|first| {
+                            HijriDate::from_day_number(first.day_number() - 1).ok()
+                        }),
+                    }
+                };
+                dates.extend(found.filter(|date| {
+                    self.by_weekday.is_empty() || self.by_weekday.contains(&date.weekday())
+                }));
+            }
+        } else {
+            dates.extend(
+                (1..=month_len)
+                    .filter_map(date)
+                    .filter(|date| self.by_weekday.contains(&date.weekday())),
+            );
+        }
+    }
+}
+
+/// Iterator over the occurrences of a [`Recurrence`].
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    rule: &'a Recurrence,
+    start: HijriDate,
+    period: usize,
+    pending: VecDeque<HijriDate>,
+    emitted: usize,
+    done: bool,
+}
+
+impl<'a> Iterator for Occurrences<'a> {
+    type Item = HijriDate;
+
+    fn next(&mut self) -> Option<HijriDate> {
+        loop {
+            if self.done || Some(self.emitted) == self.rule.count {
+                return None;
+            }
+
+            if let Some(date) = self.pending.pop_front() {
+                if let Some(until) = &self.rule.until {
+                    if date > *until {
+                        self.done = true;
+                        return None;
+                    }
+                }
+                self.emitted += 1;
+                return Some(date);
+            }
+
+            match self.rule.period(&self.start, self.period) {
+                Some(dates) => {
+                    let start = &self.start;
+                    self.pending
+                        .extend(dates.into_iter().filter(|date| date >= start));
+                }
+                None => self.done = true,
+            }
+            self.period += 1;
+        }
+    }
+}
@@ -0,0 +1,466 @@
+use crate::extended::{MAX_JDN, MIN_JDN};
+use crate::month::GREGORIAN_MONTHS;
+use crate::{DayNumber, HijriDate, HijriWeekday, NumberingSystem};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Algorithm deciding the leap years of the solar hijri calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolarAlgorithm {
+    /// The year starts on the day of the vernal equinox if it happens before noon in Tehran
+    /// (UTC+3:30), on the next day otherwise. This is the official rule in Iran and
+    /// Afghanistan, handled for the years 1 to 2378.
+    Astronomical,
+    /// 8 leap years in every cycle of 33 years, the years where `(25 * year + 11) % 33 < 8`
+    Arithmetic,
+}
+
+/// Language of the solar hijri names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolarLocale {
+    /// Iranian month names
+    Persian,
+    /// Afghan month names, from the zodiac
+    Dari,
+    Pashto,
+    English,
+}
+
+/// Date of the solar hijri (Persian, Jalali) calendar.
+///
+/// Years start at the vernal equinox and are counted from the Hijra. The first 6 months have
+/// 31 days, the next 5 have 30 days and Esfand has 29 days, 30 in leap years.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, SolarAlgorithm, SolarHijriDate, SolarLocale};
+///
+/// let nowruz = SolarHijriDate::from_gr(2024, 3, 20, SolarAlgorithm::Astronomical).unwrap();
+/// assert_eq!((nowruz.year(), nowruz.month(), nowruz.day()), (1403, 1, 1));
+/// assert_eq!(nowruz.format("%d %M %Y", SolarLocale::Persian), "1 فروردین 1403");
+/// assert_eq!(nowruz.format("%d %M %Y", SolarLocale::Dari), "1 حمل 1403");
+///
+/// let hd = nowruz.to_hijri().unwrap();
+/// assert_eq!(hd.format("%Y-%m-%d"), "1445-9-10");
+/// assert_eq!(hd.to_solar(SolarAlgorithm::Astronomical), Ok(nowruz));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SolarHijriDate {
+    year: i32,
+    month: usize,
+    day: usize,
+    day_number: DayNumber,
+    algorithm: SolarAlgorithm,
+}
+
+// Julian Day Number of 1 Farvardin 1 with the arithmetic algorithm
+const ARITHMETIC_EPOCH_JDN: i64 = 1_948_320;
+
+// years handled by the equinox computation
+const ASTRONOMICAL_YEARS: (i32, i32) = (1, 2378);
+
+impl SolarHijriDate {
+    /// get data from a solar hijri date
+    pub fn new(
+        year: i32,
+        month: usize,
+        day: usize,
+        algorithm: SolarAlgorithm,
+    ) -> Result<SolarHijriDate, String> {
+        if !(1..=12).contains(&month) {
+            bail!("enter a valid month, Err m = {}", month);
+        }
+        check_year(year, algorithm)?;
+        let first = new_year(year as i64, algorithm);
+        let month_len = month_len(month, first, new_year(year as i64 + 1, algorithm));
+        if !(1..=month_len).contains(&day) {
+            bail!(
+                "enter a valid day, {}-{} has {} days, Err d = {}",
+                year,
+                month,
+                month_len,
+                day
+            );
+        }
+        let day_of_year = if month <= 6 {
+            31 * (month - 1)
+        } else {
+            186 + 30 * (month - 7)
+        } + day
+            - 1;
+        let jdn = first + day_of_year as i64;
+        if !(MIN_JDN..=MAX_JDN).contains(&jdn) {
+            bail!("julian day number {} is out of range", jdn);
+        }
+
+        Ok(SolarHijriDate {
+            year,
+            month,
+            day,
+            day_number: DayNumber::from_jdn(jdn),
+            algorithm,
+        })
+    }
+
+    /// get data from a day number.
+    pub fn from_day_number(
+        day_number: DayNumber,
+        algorithm: SolarAlgorithm,
+    ) -> Result<SolarHijriDate, String> {
+        let jdn = day_number.jdn();
+        if !(MIN_JDN..=MAX_JDN).contains(&jdn) {
+            bail!("julian day number {} is out of range", jdn);
+        }
+        let mut year = ((jdn - ARITHMETIC_EPOCH_JDN) as f64 / 365.2422).floor() as i64 + 1;
+        // the estimate is at most a year off, the equinox is only computed for handled years
+        let (min, max) = ASTRONOMICAL_YEARS;
+        if algorithm == SolarAlgorithm::Astronomical
+            && !(min as i64 - 1..=max as i64 + 1).contains(&year)
+        {
+            check_year(year as i32, algorithm)?;
+        }
+        while new_year(year, algorithm) > jdn {
+            year -= 1;
+        }
+        while new_year(year + 1, algorithm) <= jdn {
+            year += 1;
+        }
+        let day_of_year = (jdn - new_year(year, algorithm)) as usize;
+        let year = match i32::try_from(year) {
+            Ok(year) => year,
+            Err(_) => bail!("julian day number {} is out of range", jdn),
+        };
+        check_year(year, algorithm)?;
+
+        let (month, day) = if day_of_year < 186 {
+            (day_of_year / 31 + 1, day_of_year % 31 + 1)
+        } else {
+            ((day_of_year - 186) / 30 + 7, (day_of_year - 186) % 30 + 1)
+        };
+        Ok(SolarHijriDate {
+            year,
+            month,
+            day,
+            day_number,
+            algorithm,
+        })
+    }
+
+    /// get data from gregorian date.
+    pub fn from_gr(
+        year_gr: i32,
+        month_gr: usize,
+        day_gr: usize,
+        algorithm: SolarAlgorithm,
+    ) -> Result<SolarHijriDate, String> {
+        match DayNumber::from_gregorian(year_gr, month_gr as u32, day_gr as u32) {
+            Some(day_number) => SolarHijriDate::from_day_number(day_number, algorithm),
+            None => bail!("Wrong gegorean date foramt"),
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> usize {
+        self.month
+    }
+
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    pub fn algorithm(&self) -> SolarAlgorithm {
+        self.algorithm
+    }
+
+    pub fn day_number(&self) -> DayNumber {
+        self.day_number
+    }
+
+    pub fn weekday(&self) -> HijriWeekday {
+        self.day_number.weekday()
+    }
+
+    /// Number of days in the month
+    pub fn month_len(&self) -> usize {
+        self.month_len_of(self.month)
+    }
+
+    /// Whether Esfand has 30 days
+    pub fn is_leap_year(&self) -> bool {
+        self.month_len_of(12) == 30
+    }
+
+    /// Day of the year, starting from 1 for 1 Farvardin
+    pub fn day_of_year(&self) -> usize {
+        if self.month <= 6 {
+            31 * (self.month - 1) + self.day
+        } else {
+            186 + 30 * (self.month - 7) + self.day
+        }
+    }
+
+    /// Gregorian (year, month, day), year 0 being 1 BCE
+    pub fn to_gregorian(&self) -> (i32, usize, usize) {
+        let (year, month, day) = self.day_number.gregorian();
+        (year, month as usize, day as usize)
+    }
+
+    /// Lunar hijri date of the same day, see [`HijriDate::from_day_number`]
+    pub fn to_hijri(&self) -> Result<HijriDate, String> {
+        HijriDate::from_day_number(self.day_number)
+    }
+
+    /// Lunar hijri date of the same day, see [`HijriDate::from_day_number_extended`]
+    pub fn to_hijri_extended(&self) -> Result<HijriDate, String> {
+        HijriDate::from_day_number_extended(self.day_number)
+    }
+
+    /// Name of the month
+    pub fn month_name(&self, locale: SolarLocale) -> &'static str {
+        let names = match locale {
+            SolarLocale::Persian => &PERSIAN_MONTHS,
+            SolarLocale::Dari => &DARI_MONTHS,
+            SolarLocale::Pashto => &PASHTO_MONTHS,
+            SolarLocale::English => &ENGLISH_MONTHS,
+        };
+        names[self.month - 1]
+    }
+
+    /// Name of the day of the week
+    pub fn day_name(&self, locale: SolarLocale) -> &'static str {
+        let index = self.weekday().number() - 1;
+        match locale {
+            SolarLocale::Persian | SolarLocale::Dari => PERSIAN_WEEKDAYS[index],
+            SolarLocale::Pashto => PASHTO_WEEKDAYS[index],
+            SolarLocale::English => self.weekday().name(crate::Locale::English),
+        }
+    }
+
+    /// Returns a representation of the date defined by the given formatter, with the
+    /// specifiers of [`HijriDate::format`] (`%Y`, `%m`, `%d`, `%D`, `%M`, `%l` for the solar
+    /// date and `%gY`, `%gm`, `%gd`, `%gD`, `%gM` for the gregorian one)
+    pub fn format(&self, f: &str, locale: SolarLocale) -> String {
+        self.format_with(f, locale, NumberingSystem::Latin)
+    }
+
+    /// Same as [`SolarHijriDate::format`], with the numbers written in the given numbering
+    /// system
+    pub fn format_with(&self, f: &str, locale: SolarLocale, numbering: NumberingSystem) -> String {
+        let number = |n: &dyn ToString| numbering.apply(&n.to_string());
+        let (year_gr, month_gr, day_gr) = self.to_gregorian();
+
+        f.replace("%Y", &number(&self.year))
+            .replace("%m", &number(&self.month))
+            .replace("%d", &number(&self.day))
+            .replace("%D", self.day_name(locale))
+            .replace("%M", self.month_name(locale))
+            .replace("%l", &number(&self.month_len()))
+            .replace("%gY", &number(&year_gr))
+            .replace("%gm", &number(&month_gr))
+            .replace("%gd", &number(&day_gr))
+            .replace("%gD", self.day_name(SolarLocale::English))
+            .replace("%gM", GREGORIAN_MONTHS[month_gr - 1])
+    }
+
+    fn month_len_of(&self, month: usize) -> usize {
+        // the next year is handled, as the date exists
+        let first = self.day_number.jdn() - self.day_of_year() as i64 + 1;
+        let next_year = new_year(self.year as i64 + 1, self.algorithm);
+        month_len(month, first, next_year)
+    }
+}
+
+/// `year-month-day` of the solar hijri date
+impl fmt::Display for SolarHijriDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl HijriDate {
+    /// Solar hijri date of the same day
+    pub fn to_solar(&self, algorithm: SolarAlgorithm) -> Result<SolarHijriDate, String> {
+        SolarHijriDate::from_day_number(self.day_number, algorithm)
+    }
+}
+
+fn month_len(month: usize, new_year: i64, next_year: i64) -> usize {
+    match month {
+        1..=6 => 31,
+        7..=11 => 30,
+        _ => (next_year - new_year) as usize - 336,
+    }
+}
+
+fn check_year(year: i32, algorithm: SolarAlgorithm) -> Result<(), String> {
+    let (min, max) = ASTRONOMICAL_YEARS;
+    if algorithm == SolarAlgorithm::Astronomical && !(min..=max).contains(&year) {
+        bail!(
+            "the astronomical algorithm handles the solar hijri years {} to {}, input: {}",
+            min,
+            max,
+            year
+        );
+    }
+    Ok(())
+}
+
+// Julian Day Number of 1 Farvardin of `year`
+fn new_year(year: i64, algorithm: SolarAlgorithm) -> i64 {
+    match algorithm {
+        SolarAlgorithm::Arithmetic => {
+            let years = year - 1;
+            let cycles = years.div_euclid(33);
+            let leap_years = cycles * 8
+                + (cycles * 33 + 1..=years)
+                    .filter(|&y| is_arithmetic_leap_year(y))
+                    .count() as i64;
+            ARITHMETIC_EPOCH_JDN + 365 * years + leap_years
+        }
+        SolarAlgorithm::Astronomical => {
+            // Tehran standard time is UTC+3:30, the julian day starts at noon
+            let local = march_equinox(year as i32 + 621) + 3.5 / 24.0 + 0.5;
+            let day = local.floor();
+            if local - day < 0.5 {
+                day as i64
+            } else {
+                day as i64 + 1
+            }
+        }
+    }
+}
+
+fn is_arithmetic_leap_year(year: i64) -> bool {
+    (25 * year + 11).rem_euclid(33) < 8
+}
+
+// Julian Date (UT) of the march equinox of a gregorian year, from Meeus, Astronomical
+// Algorithms, chapter 27
+fn march_equinox(year: i32) -> f64 {
+    let jde0 = if year < 1000 {
+        let y = year as f64 / 1000.0;
+        1_721_139.291_89 + 365_242.137_40 * y + 0.061_34 * y.powi(2) + 0.001_11 * y.powi(3)
+            - 0.000_71 * y.powi(4)
+    } else {
+        let y = (year as f64 - 2000.0) / 1000.0;
+        2_451_623.809_84 + 365_242.374_04 * y + 0.051_69 * y.powi(2)
+            - 0.004_11 * y.powi(3)
+            - 0.000_57 * y.powi(4)
+    };
+
+    let t = (jde0 - 2_451_545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = EQUINOX_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+    let jde = jde0 + 0.00001 * s / lambda;
+
+    // rough delta T in seconds, a minute off at most around the present
+    let u = (year as f64 - 1820.0) / 100.0;
+    let delta_t = -20.0 + 32.0 * u * u;
+    jde - delta_t / 86400.0
+}
+
+const EQUINOX_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445_267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+const PERSIAN_MONTHS: [&str; 12] = [
+    "فروردین",
+    "اردیبهشت",
+    "خرداد",
+    "تیر",
+    "مرداد",
+    "شهریور",
+    "مهر",
+    "آبان",
+    "آذر",
+    "دی",
+    "بهمن",
+    "اسفند",
+];
+
+const DARI_MONTHS: [&str; 12] = [
+    "حمل",
+    "ثور",
+    "جوزا",
+    "سرطان",
+    "اسد",
+    "سنبله",
+    "میزان",
+    "عقرب",
+    "قوس",
+    "جدی",
+    "دلو",
+    "حوت",
+];
+
+const PASHTO_MONTHS: [&str; 12] = [
+    "وری",
+    "غویی",
+    "غبرگولی",
+    "چنګاښ",
+    "زمری",
+    "وږی",
+    "تله",
+    "لړم",
+    "لیندۍ",
+    "مرغومی",
+    "سلواغه",
+    "کب",
+];
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+// from Sunday
+const PERSIAN_WEEKDAYS: [&str; 7] = [
+    "یکشنبه",
+    "دوشنبه",
+    "سه‌شنبه",
+    "چهارشنبه",
+    "پنجشنبه",
+    "جمعه",
+    "شنبه",
+];
+
+const PASHTO_WEEKDAYS: [&str; 7] = ["اتوار", "ګل", "نهه", "شورو", "زیارت", "جمعه", "خالي"];
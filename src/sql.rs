@@ -0,0 +1,226 @@
+use crate::{DayNumber, HijriDate, Locale};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+// julian day number of 2000-01-01, day 0 of the postgres dates
+const PG_EPOCH_JDN: i64 = 2_451_545;
+
+/// Hijri date stored as text in a Hijri-native column, example `1445-09-01`.
+///
+/// [`HijriDate`] itself is stored as a gregorian `date`. Extended dates (see
+/// [`HijriDate::from_hijri_extended`]) are written with their era, example `0005-09-01 BH`, and
+/// the text is read with [`HijriDate`]'s `FromStr`.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriText};
+///
+/// let text = HijriText(HijriDate::from_hijri(1445, 9, 1).unwrap());
+/// assert_eq!(text.to_string(), "1445-09-01");
+/// assert_eq!("1445-09-01".parse(), Ok(text));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HijriText(pub HijriDate);
+
+impl From<HijriDate> for HijriText {
+    fn from(date: HijriDate) -> Self {
+        HijriText(date)
+    }
+}
+
+impl From<HijriText> for HijriDate {
+    fn from(text: HijriText) -> Self {
+        text.0
+    }
+}
+
+impl fmt::Display for HijriText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let date = &self.0;
+        if !date.extended {
+            return write!(f, "{:04}-{:02}-{:02}", date.year, date.month, date.day);
+        }
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {}",
+            date.year_of_era(),
+            date.month,
+            date.day,
+            date.era().suffix(Locale::English)
+        )
+    }
+}
+
+impl FromStr for HijriText {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(HijriText)
+    }
+}
+
+impl HijriDate {
+    /// Days since 2000-01-01, the binary encoding of the postgres `date`
+    fn to_pg_days(&self) -> Result<i32, String> {
+        let days = self.day_number.jdn() - PG_EPOCH_JDN;
+        i32::try_from(days)
+            .map_err(|_| format!("{} is out of the range of the postgres dates", days))
+    }
+
+    fn from_pg_days(days: i32) -> Result<HijriDate, String> {
+        HijriDate::from_day_number(DayNumber::from_jdn(PG_EPOCH_JDN + days as i64))
+    }
+
+    /// Parses the text encoding of the postgres `date`, example `2024-03-11` or `0044-03-15 BC`
+    #[cfg(feature = "sqlx")]
+    fn from_pg_text(s: &str) -> Result<HijriDate, String> {
+        let (date, bc) = match s.strip_suffix(" BC") {
+            Some(date) => (date, true),
+            None => (s, false),
+        };
+        let parts: Vec<i32> = date
+            .split('-')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("Wrong postgres date format: {}", s))?;
+        let (year, month, day) = match parts[..] {
+            [year, month, day] if month > 0 && day > 0 => (year, month as usize, day as usize),
+            _ => bail!("Wrong postgres date format: {}", s),
+        };
+        let year = if bc { 1 - year } else { year };
+        HijriDate::from_gr(year, month, day)
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use super::HijriText;
+    use crate::HijriDate;
+    use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+    use std::convert::TryInto;
+    use std::error::Error;
+
+    type BoxError = Box<dyn Error + Sync + Send>;
+
+    /// Stored as the gregorian `date` of the same day
+    impl ToSql for HijriDate {
+        fn to_sql(&self, _: &Type, out: &mut bytes::BytesMut) -> Result<IsNull, BoxError> {
+            out.extend_from_slice(&self.to_pg_days()?.to_be_bytes());
+            Ok(IsNull::No)
+        }
+
+        accepts!(DATE);
+        to_sql_checked!();
+    }
+
+    /// Fails for days out of the table, including the infinite dates
+    impl<'a> FromSql<'a> for HijriDate {
+        fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+            let days = raw.try_into().map_err(|_| "invalid postgres date")?;
+            Ok(HijriDate::from_pg_days(i32::from_be_bytes(days))?)
+        }
+
+        accepts!(DATE);
+    }
+
+    impl ToSql for HijriText {
+        fn to_sql(&self, ty: &Type, out: &mut bytes::BytesMut) -> Result<IsNull, BoxError> {
+            self.to_string().to_sql(ty, out)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <String as ToSql>::accepts(ty)
+        }
+
+        to_sql_checked!();
+    }
+
+    impl<'a> FromSql<'a> for HijriText {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+            Ok(<&str as FromSql>::from_sql(ty, raw)?.parse()?)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <&str as FromSql>::accepts(ty)
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_postgres {
+    use super::HijriText;
+    use crate::HijriDate;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::types::Oid;
+    use sqlx::postgres::{
+        PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+    };
+    use sqlx::{Decode, Encode, Type};
+
+    const DATE_OID: Oid = Oid(1082);
+    const DATE_ARRAY_OID: Oid = Oid(1182);
+
+    /// Stored as the gregorian `date` of the same day
+    impl Type<Postgres> for HijriDate {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(DATE_OID)
+        }
+    }
+
+    impl PgHasArrayType for HijriDate {
+        fn array_type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(DATE_ARRAY_OID)
+        }
+    }
+
+    impl Encode<'_, Postgres> for HijriDate {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            Encode::<Postgres>::encode(self.to_pg_days()?, buf)
+        }
+
+        fn size_hint(&self) -> usize {
+            std::mem::size_of::<i32>()
+        }
+    }
+
+    /// Fails for days out of the table, including the infinite dates
+    impl<'r> Decode<'r, Postgres> for HijriDate {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(match value.format() {
+                PgValueFormat::Binary => {
+                    HijriDate::from_pg_days(Decode::<Postgres>::decode(value)?)?
+                }
+                PgValueFormat::Text => HijriDate::from_pg_text(value.as_str()?)?,
+            })
+        }
+    }
+
+    impl Type<Postgres> for HijriText {
+        fn type_info() -> PgTypeInfo {
+            <str as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <str as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl PgHasArrayType for HijriText {
+        fn array_type_info() -> PgTypeInfo {
+            <&str as PgHasArrayType>::array_type_info()
+        }
+    }
+
+    impl Encode<'_, Postgres> for HijriText {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            Encode::<Postgres>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for HijriText {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(<&str as Decode<Postgres>>::decode(value)?.parse()?)
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::bidi::{isolate_ltr, isolate_rtl, ALM};
+use crate::month::GREGORIAN_MONTHS;
+use crate::{Era, HijriDate, Locale, NumberingSystem};
+use std::fmt;
+
+/// Length of a predefined date format, similar to `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatStyle {
+    /// `1/9/1445`
+    Short,
+    /// `1 Ram. 1445 AH`
+    Medium,
+    /// `1 Ramadan 1445 AH`
+    Long,
+    /// `Monday, 1 Ramadan 1445 AH`
+    Full,
+}
+
+/// Predefined, locale-aware date format.
+///
+/// The era suffix (`AH` / `هـ`) is shown for every style except [`FormatStyle::Short`], and
+/// for every year before the epoch. The gregorian date can be appended.
+///
+/// ```rust
+/// use hijri_date::{DateFormat, FormatStyle, HijriDate, Locale};
+///
+/// let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+/// let medium = DateFormat::new(FormatStyle::Medium, Locale::English);
+/// assert_eq!(medium.format(&hd), "1 Ram. 1445 AH");
+///
+/// let dual = DateFormat::new(FormatStyle::Long, Locale::Arabic).gregorian(true);
+/// assert_eq!(dual.format(&hd), "1 رمضان 1445 هـ الموافق 11 مارس 2024 م");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateFormat {
+    style: FormatStyle,
+    locale: Locale,
+    numbering: NumberingSystem,
+    era: bool,
+    gregorian: bool,
+    bidi: bool,
+}
+
+/// Full arabic format with the gregorian date, used by `Display`
+impl Default for DateFormat {
+    fn default() -> Self {
+        DateFormat::new(FormatStyle::Full, Locale::Arabic).gregorian(true)
+    }
+}
+
+impl DateFormat {
+    pub fn new(style: FormatStyle, locale: Locale) -> Self {
+        Self {
+            style,
+            locale,
+            numbering: NumberingSystem::Latin,
+            era: style != FormatStyle::Short,
+            gregorian: false,
+            bidi: false,
+        }
+    }
+
+    pub fn numbering(mut self, numbering: NumberingSystem) -> Self {
+        self.numbering = numbering;
+        self
+    }
+
+    /// Show or hide the era suffix
+    pub fn era(mut self, era: bool) -> Self {
+        self.era = era;
+        self
+    }
+
+    /// Append the gregorian date
+    pub fn gregorian(mut self, gregorian: bool) -> Self {
+        self.gregorian = gregorian;
+        self
+    }
+
+    /// Isolate the output in its direction (right-to-left for arabic) and add arabic letter
+    /// marks between the numbers of the short arabic style
+    pub fn bidi(mut self, bidi: bool) -> Self {
+        self.bidi = bidi;
+        self
+    }
+
+    pub fn format(&self, date: &HijriDate) -> String {
+        let hijri = self.date(
+            date.day(),
+            date.month(),
+            Era::hijri(date.year()),
+            &self.hijri_month_name(date),
+        );
+        let mut s = if self.style == FormatStyle::Full {
+            match self.locale {
+                Locale::Arabic => format!("{}، {}", date.weekday().name(self.locale), hijri),
+                Locale::English => format!("{}, {}", date.weekday().name(self.locale), hijri),
+            }
+        } else {
+            hijri
+        };
+
+        if self.gregorian {
+            let gregorian = self.date(
+                date.day_gr(),
+                date.month_gr(),
+                Era::gregorian(date.year_gr()),
+                &self.gregorian_month_name(date),
+            );
+            match self.locale {
+                Locale::Arabic => s = format!("{} الموافق {}", s, gregorian),
+                Locale::English => s = format!("{} ({})", s, gregorian),
+            }
+        }
+
+        match (self.bidi, self.locale) {
+            (false, _) => s,
+            (true, Locale::Arabic) => isolate_rtl(&s),
+            (true, Locale::English) => isolate_ltr(&s),
+        }
+    }
+
+    // years before the epoch always show their era
+    fn date(&self, day: usize, month: usize, (era, year): (Era, u32), name: &str) -> String {
+        let number = |n: &dyn ToString| self.numbering.apply(&n.to_string());
+        let mut s = match self.style {
+            FormatStyle::Short if self.bidi && self.locale == Locale::Arabic => format!(
+                "{}{}/{}{}/{}",
+                number(&day),
+                ALM,
+                number(&month),
+                ALM,
+                number(&year)
+            ),
+            FormatStyle::Short => format!("{}/{}/{}", number(&day), number(&month), number(&year)),
+            _ => format!("{} {} {}", number(&day), name, number(&year)),
+        };
+        if self.era || era.is_before_epoch() {
+            s.push(' ');
+            s.push_str(era.suffix(self.locale));
+        }
+        s
+    }
+
+    fn hijri_month_name(&self, date: &HijriDate) -> String {
+        match (self.style, self.locale) {
+            (FormatStyle::Medium, Locale::English) => {
+                HIJRI_ABBREVIATIONS[date.month() - 1].to_string()
+            }
+            (_, locale) => date.month_enum().name(locale).to_string(),
+        }
+    }
+
+    fn gregorian_month_name(&self, date: &HijriDate) -> String {
+        match (self.style, self.locale) {
+            (_, Locale::Arabic) => GREGORIAN_ARABIC[date.month_gr() - 1].to_string(),
+            (FormatStyle::Medium, Locale::English) => {
+                GREGORIAN_MONTHS[date.month_gr() - 1][..3].to_string()
+            }
+            (_, Locale::English) => date.month_name_en(),
+        }
+    }
+}
+
+const HIJRI_ABBREVIATIONS: [&str; 12] = [
+    "Muh.", "Saf.", "Rab. I", "Rab. II", "Jum. I", "Jum. II", "Raj.", "Sha.", "Ram.", "Shaw.",
+    "Dhu'l-Q.", "Dhu'l-H.",
+];
+
+const GREGORIAN_ARABIC: [&str; 12] = [
+    "يناير",
+    "فبراير",
+    "مارس",
+    "أبريل",
+    "مايو",
+    "يونيو",
+    "يوليو",
+    "أغسطس",
+    "سبتمبر",
+    "أكتوبر",
+    "نوفمبر",
+    "ديسمبر",
+];
+
+/// Date displayed with a [`DateFormat`], see [`HijriDate::styled`]
+#[derive(Debug, Clone)]
+pub struct Styled<'a> {
+    date: &'a HijriDate,
+    format: DateFormat,
+}
+
+/// The alternate flag (`{:#}`) enables [`DateFormat::bidi`]
+impl<'a> fmt::Display for Styled<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = if f.alternate() {
+            self.format.bidi(true)
+        } else {
+            self.format
+        };
+        f.write_str(&format.format(self.date))
+    }
+}
+
+impl HijriDate {
+    /// Formats the date with a predefined style
+    pub fn format_style(&self, style: FormatStyle, locale: Locale) -> String {
+        DateFormat::new(style, locale).format(self)
+    }
+
+    /// Wraps the date to display it with the given format
+    pub fn styled(&self, format: DateFormat) -> Styled<'_> {
+        Styled { date: self, format }
+    }
+}
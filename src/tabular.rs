@@ -0,0 +1,36 @@
+//! Arithmetic (tabular) islamic calendar, used for the dates outside the Umm al-Qura table.
+//!
+//! Years of 354 or 355 days in a 30 years cycle, the leap years being 2, 5, 7, 10, 13, 16, 18,
+//! 21, 24, 26 and 29. Odd months have 30 days, even months 29 days and Dhu al-Hijjah 30 days in
+//! leap years. Years are astronomical, year 0 being 1 BH.
+
+/// Julian Day Number of 1 Muharram 1 AH, 622-07-16 (julian calendar)
+pub const EPOCH_JDN: i64 = 1_948_440;
+
+pub const fn is_leap_year(year: i64) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+pub const fn month_len(year: i64, month: usize) -> usize {
+    if month % 2 == 1 || (month == 12 && is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+/// Julian Day Number of a date, the month and day must be valid
+pub const fn to_jdn(year: i64, month: usize, day: usize) -> i64 {
+    let days_before_year = 354 * (year - 1) + (3 + 11 * year).div_euclid(30);
+    let days_before_month = (29 * (month - 1) + month / 2) as i64;
+    EPOCH_JDN - 1 + days_before_year + days_before_month + day as i64
+}
+
+/// (year, month, day, month length) of a Julian Day Number
+pub const fn from_jdn(jdn: i64) -> (i64, usize, usize, usize) {
+    let year = (30 * (jdn - EPOCH_JDN) + 10646).div_euclid(10631);
+    let prior_days = jdn - to_jdn(year, 1, 1);
+    let month = ((11 * prior_days + 330) / 325) as usize;
+    let day = (jdn - to_jdn(year, month, 1) + 1) as usize;
+    (year, month, day, month_len(year, month))
+}
@@ -1,75 +1,79 @@
 use crate::umalqura_array::*;
 
-pub fn gegorean_to_hijri(
-    mut year_gr: usize,
-    mut month_gr: usize,
-    day_gr: usize,
-) -> (usize, usize, usize, usize) {
-    //This code the modified version of R.H. van Gent Code, it can be found at http://www.staff.science.uu.nl/~gent0113/islam/ummalqura.htm
+/// Modified Chronological Julian Day Number of the first day handled by the table
+pub const MIN_MCJDN: usize = UMALQURA_DAT[0];
 
-    //append January and February to the previous year (i.e. regard March as
-    // the first month of the year in order to simplify leapday corrections)
+/// Modified Chronological Julian Day Number of the last day handled by the table
+pub const MAX_MCJDN: usize = UMALQURA_DAT[UMALQURA_DAT.len() - 1] - 1;
 
-    if month_gr < 3 {
-        year_gr -= 1;
-        month_gr += 12;
-    }
-
-    //determine offset between Julian and Gregorian calendar
-    let a = (year_gr / 100) as f64;
-    let jgc = a - a / 4.0 - 2.0;
-    let (y, m, d) = (year_gr as f64, month_gr as f64, day_gr as f64);
-
-    //compute Chronological Julian Day Number (CJDN)
-    let cjdn = (365.25 * (y + 4716.0)).floor() + (30.6001 * (m + 1.0)).floor() + d - jgc - 1524.0;
+/// Julian Day Number of Modified Chronological Julian Day Number 0
+pub const MCJDN_EPOCH: i64 = 2_400_000;
 
-    // compute Modified Chronological Julian Day Number (MCJDN)
-    let mcjdn = cjdn - 2_400_000.0;
+// hijri lunation number of the month starting at `UMALQURA_DAT[0]`, minus one
+const ILN_OFFSET: usize = 16260;
 
-    // should not fail
-    let index = umalqura_index(mcjdn).unwrap();
+/// Returns the hijri (year, month, day, month length) of a day handled by the table
+pub const fn mcjdn_to_hijri(mcjdn: usize) -> (usize, usize, usize, usize) {
+    let index = umalqura_index(mcjdn);
 
     //compute and output the Umm al-Qura calendar date
-    let iln = index + 16260;
+    let iln = index + ILN_OFFSET;
     let ii = (iln - 1) / 12;
     let iy = ii + 1;
     let im = iln - 12 * ii;
-    let id = mcjdn - UMALQURA_DAT[index - 1] as f64 + 1.0;
+    let id = mcjdn - UMALQURA_DAT[index - 1] + 1;
     let ml = UMALQURA_DAT[index] - UMALQURA_DAT[index - 1];
 
-    (iy, im, id as usize, ml)
+    (iy, im, id, ml)
 }
 
-pub fn hijri_to_gregorian(year: usize, month: usize, day: usize) -> (usize, usize, usize) {
-    let ii = year - 1;
-    let iln = (ii * 12) + 1 + (month - 1);
-    let i: usize = iln - 16260;
-    let mcjdn = day + UMALQURA_DAT[i - 1] - 1;
-    let cjdn = mcjdn + 2_400_000;
-
-    julian_to_gregorian(cjdn as f64)
+/// Index `i` of the table such that the month starts at `UMALQURA_DAT[i - 1]`,
+/// `None` if the month isn't handled
+pub const fn month_index(year: usize, month: usize) -> Option<usize> {
+    // large years overflow a 32 bits usize, they are out of the table anyway
+    let months = match year.checked_mul(12) {
+        Some(months) => months.checked_add(month),
+        None => None,
+    };
+    let iln = match months {
+        Some(months) => months.saturating_sub(12),
+        None => return None,
+    };
+    if iln <= ILN_OFFSET || iln - ILN_OFFSET >= UMALQURA_DAT.len() {
+        return None;
+    }
+    Some(iln - ILN_OFFSET)
 }
 
-#[allow(clippy::many_single_char_names)]
-fn julian_to_gregorian(cjdn: f64) -> (usize, usize, usize) {
-    //source from: http://keith-wood.name/calendars.html
-
-    let z = (cjdn + 0.5).floor();
-    let a = ((z - 1_867_216.25) / 36524.25).floor();
-    let a = z + 1.0 + a - (a / 4.0).floor();
-    let b = a + 1524.0;
-    let c = ((b - 122.1) / 365.25).floor();
-    let d = (365.25 * c).floor();
-    let e = ((b - d) / 30.6001).floor();
-    let day = b - d - (e * 30.6001).floor();
-
-    let month = if e > 13.5 { e - 13.0 } else { e - 1.0 };
+/// Length of a handled month
+pub const fn month_len(index: usize) -> usize {
+    UMALQURA_DAT[index] - UMALQURA_DAT[index - 1]
+}
 
-    let mut year = if month > 2.5 { c - 4716.0 } else { c - 4715.0 };
+pub const fn hijri_to_mcjdn(index: usize, day: usize) -> usize {
+    day + UMALQURA_DAT[index - 1] - 1
+}
 
-    if year <= 0.0 {
-        year -= 1.0;
+/// Index of the month containing `mcjdn`, ie the first `i` with `UMALQURA_DAT[i] > mcjdn`
+pub const fn umalqura_index(mcjdn: usize) -> usize {
+    let (mut low, mut high) = (0, UMALQURA_DAT.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if UMALQURA_DAT[mid] > mcjdn {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
     }
+    low
+}
 
-    (year as usize, month as usize, day as usize)
+/// Modified Chronological Julian Day Numbers of 1 Muharram of `year` and of the next year,
+/// `None` if the year isn't handled
+#[cfg(feature = "std")]
+pub const fn year_bounds(year: usize) -> Option<(usize, usize)> {
+    match (month_index(year, 1), month_index(year, 12)) {
+        (Some(first), Some(last)) => Some((UMALQURA_DAT[first - 1], UMALQURA_DAT[last])),
+        _ => None,
+    }
 }
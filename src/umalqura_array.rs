@@ -1,11 +1,3 @@
-pub fn umalqura_index(mcjdn: f64) -> Result<usize, String> {
-    for (i, umalqura) in UMALQURA_DAT.iter().enumerate() {
-        if *umalqura as f64 > mcjdn {
-            return Ok(i);
-        }
-    }
-    bail!("Something went wrong in the algorithm")
-}
 pub const UMALQURA_DAT: [usize; 1741] = [
     28607, 28636, 28665, 28695, 28724, 28754, 28783, 28813, 28843, 28872, 28901, 28931, 28960,
     28990, 29019, 29049, 29078, 29108, 29137, 29167, 29196, 29226, 29255, 29285, 29315, 29345,
@@ -0,0 +1,125 @@
+use crate::umalqura::{mcjdn_to_hijri, year_bounds, MCJDN_EPOCH};
+use crate::{DayNumber, HijriDate, HijriWeekday};
+
+/// Hijri week numbering.
+///
+/// Weeks start on `first_weekday` (Saturday by default, as in the Gulf countries) and week 1
+/// is the week containing 1 Muharram. The days of that week falling in the previous year
+/// belong to week 1 of the new year, like the ISO 8601 week-based year.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriWeekday, WeekNumbering};
+///
+/// let hd = HijriDate::from_hijri(1445, 9, 6).unwrap();
+/// assert_eq!(hd.weekday(), HijriWeekday::Saturday);
+/// assert_eq!(hd.week_of_year(), (1445, 36));
+/// assert_eq!(HijriDate::from_week(1445, 36, HijriWeekday::Saturday), Ok(hd.clone()));
+///
+/// let sunday = WeekNumbering::new(HijriWeekday::Sunday);
+/// assert_eq!(sunday.week_of_year(&hd), (1445, 35));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekNumbering {
+    pub first_weekday: HijriWeekday,
+}
+
+impl Default for WeekNumbering {
+    fn default() -> Self {
+        Self {
+            first_weekday: HijriWeekday::Saturday,
+        }
+    }
+}
+
+impl WeekNumbering {
+    pub fn new(first_weekday: HijriWeekday) -> Self {
+        Self { first_weekday }
+    }
+
+    /// Returns the (week-based year, week) of the date
+    pub fn week_of_year(&self, date: &HijriDate) -> (i32, usize) {
+        let next_year = date.last_day_of_year().day_number() + 1;
+        self.week(
+            date.day_number(),
+            date.year(),
+            date.first_day_of_year().day_number(),
+            next_year,
+        )
+    }
+
+    /// (week-based year, week) of a day handled by the table, without building a date
+    pub(crate) fn week_of_mcjdn(&self, mcjdn: usize) -> (i32, usize) {
+        let day_number = |mcjdn: usize| DayNumber::from_jdn(mcjdn as i64 + MCJDN_EPOCH);
+        let (year, ..) = mcjdn_to_hijri(mcjdn);
+        // shouldn't fail, the year of a handled day is handled
+        let (first, next_year) = year_bounds(year).unwrap();
+        self.week(
+            day_number(mcjdn),
+            year as i32,
+            day_number(first),
+            day_number(next_year),
+        )
+    }
+
+    // week of `day` in `year`, starting on `first` and followed by the year starting on `next_year`
+    fn week(
+        &self,
+        day: DayNumber,
+        year: i32,
+        first: DayNumber,
+        next_year: DayNumber,
+    ) -> (i32, usize) {
+        if day >= self.week_start(next_year) {
+            return (year + 1, 1);
+        }
+        (year, ((day - self.week_start(first)) / 7 + 1) as usize)
+    }
+
+    /// Number of weeks in the week-based hijri year, 50 or 51
+    pub fn weeks_in_year(&self, year: i32) -> Result<usize, String> {
+        let first = HijriDate::from_hijri(year, 1, 1)?;
+        let next_year = first.last_day_of_year().day_number() + 1;
+        let weeks = self.week_start(next_year) - self.week_start(first.day_number());
+        Ok((weeks / 7) as usize)
+    }
+
+    /// get data from a week-based year, week and day of the week.
+    pub fn from_week(
+        &self,
+        year: i32,
+        week: usize,
+        weekday: HijriWeekday,
+    ) -> Result<HijriDate, String> {
+        let weeks = self.weeks_in_year(year)?;
+        if !(1..=weeks).contains(&week) {
+            bail!(
+                "enter a valid week, {} has {} weeks, Err w = {}",
+                year,
+                weeks,
+                week
+            );
+        }
+        let start = self.week_start(HijriDate::from_hijri(year, 1, 1)?.day_number());
+        let offset = (weekday.number() + 7 - self.first_weekday.number()) % 7;
+        HijriDate::from_day_number(start + (7 * (week - 1) + offset) as i64)
+    }
+
+    /// Start of the week containing the given day
+    fn week_start(&self, day: DayNumber) -> DayNumber {
+        let offset = (day.weekday().number() + 7 - self.first_weekday.number()) % 7;
+        day - offset as i64
+    }
+}
+
+impl HijriDate {
+    /// Returns the (week-based year, week) of the date, weeks starting on Saturday.
+    /// See [`WeekNumbering`] for other first days of the week.
+    pub fn week_of_year(&self) -> (i32, usize) {
+        WeekNumbering::default().week_of_year(self)
+    }
+
+    /// get data from a week-based year, week and day of the week, weeks starting on Saturday.
+    pub fn from_week(year: i32, week: usize, weekday: HijriWeekday) -> Result<HijriDate, String> {
+        WeekNumbering::default().from_week(year, week, weekday)
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(feature = "std")]
+use crate::month::normalize;
+use crate::Locale;
+#[cfg(feature = "std")]
+use crate::NumberingSystem;
+#[cfg(feature = "chrono")]
+use chrono::Weekday;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+/// Day of the week.
+///
+/// Days are numbered from 1 for Sunday (al-ahad, "the first") to 7 for Saturday.
+///
+/// ```rust
+/// use hijri_date::{HijriDate, HijriWeekday, Locale};
+///
+/// let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+/// assert_eq!(hd.weekday(), HijriWeekday::Monday);
+/// assert_eq!(hd.weekday().name(Locale::Arabic), "الاثنين");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HijriWeekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+use HijriWeekday::*;
+
+const WEEKDAYS: [HijriWeekday; 7] = [
+    Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday,
+];
+
+// (arabic, english) for every day, in order
+const NAMES: [(&str, &str); 7] = [
+    ("الاحد", "Sunday"),
+    ("الاثنين", "Monday"),
+    ("الثلاثاء", "Tuesday"),
+    ("الاربعاء", "Wednesday"),
+    ("الخميس", "Thursday"),
+    ("الجمعة", "Friday"),
+    ("السبت", "Saturday"),
+];
+
+impl HijriWeekday {
+    /// Day number, starting from 1 for Sunday
+    pub fn number(self) -> usize {
+        self as usize + 1
+    }
+
+    /// Day from its number (1 to 7)
+    pub fn from_number(n: usize) -> Option<HijriWeekday> {
+        WEEKDAYS.get(n.checked_sub(1)?).copied()
+    }
+
+    /// Next day, Sunday follows Saturday
+    pub fn succ(self) -> HijriWeekday {
+        WEEKDAYS[(self as usize + 1) % 7]
+    }
+
+    /// Previous day, Saturday precedes Sunday
+    pub fn pred(self) -> HijriWeekday {
+        WEEKDAYS[(self as usize + 6) % 7]
+    }
+
+    /// Name of the day in the given locale
+    pub fn name(self, locale: Locale) -> &'static str {
+        let (ar, en) = NAMES[self as usize];
+        match locale {
+            Locale::Arabic => ar,
+            Locale::English => en,
+        }
+    }
+}
+
+/// Parses the day number, its arabic name or its english name (case, spaces and punctuation
+/// are ignored).
+#[cfg(feature = "std")]
+impl FromStr for HijriWeekday {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(n) = NumberingSystem::to_latin(s).parse() {
+            return HijriWeekday::from_number(n)
+                .ok_or(format!("enter a valid weekday, Err d = {}", n));
+        }
+
+        let key = normalize(s);
+        NAMES
+            .iter()
+            .position(|(ar, en)| key == normalize(ar) || key == normalize(en))
+            .map(|i| WEEKDAYS[i])
+            .ok_or(format!("unknown weekday: {}", s))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Weekday> for HijriWeekday {
+    fn from(weekday: Weekday) -> Self {
+        WEEKDAYS[weekday.num_days_from_sunday() as usize]
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<HijriWeekday> for Weekday {
+    fn from(weekday: HijriWeekday) -> Self {
+        match weekday {
+            Sunday => Weekday::Sun,
+            Monday => Weekday::Mon,
+            Tuesday => Weekday::Tue,
+            Wednesday => Weekday::Wed,
+            Thursday => Weekday::Thu,
+            Friday => Weekday::Fri,
+            Saturday => Weekday::Sat,
+        }
+    }
+}
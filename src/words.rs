@@ -0,0 +1,321 @@
+//! Numbers spelled out in Arabic and English, used to write dates in words.
+//!
+//! Arabic numbers agree with the gender of the counted noun, the tens are in the oblique case
+//! (`عشرين`) as in dates where they follow a preposition.
+//!
+//! ```rust
+//! use hijri_date::words::{arabic_cardinal, arabic_ordinal, english_ordinal, Gender};
+//!
+//! assert_eq!(arabic_cardinal(1445, Gender::Masculine), "ألف وأربعمائة وخمسة وأربعين");
+//! assert_eq!(arabic_cardinal(13, Gender::Feminine), "ثلاث عشرة");
+//! assert_eq!(arabic_ordinal(21, Gender::Masculine).unwrap(), "الحادي والعشرون");
+//! assert_eq!(english_ordinal(21).unwrap(), "twenty-first");
+//! ```
+
+use crate::{Era, HijriDate, Locale};
+
+/// Grammatical gender of the counted noun.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+}
+
+// forms of the units from 1 to 10 counting a masculine noun, then a feminine one
+const UNITS_MASCULINE: [&str; 11] = [
+    "",
+    "واحد",
+    "اثنين",
+    "ثلاثة",
+    "أربعة",
+    "خمسة",
+    "ستة",
+    "سبعة",
+    "ثمانية",
+    "تسعة",
+    "عشرة",
+];
+const UNITS_FEMININE: [&str; 11] = [
+    "",
+    "واحدة",
+    "اثنتين",
+    "ثلاث",
+    "أربع",
+    "خمس",
+    "ست",
+    "سبع",
+    "ثماني",
+    "تسع",
+    "عشر",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "عشرة",
+    "عشرين",
+    "ثلاثين",
+    "أربعين",
+    "خمسين",
+    "ستين",
+    "سبعين",
+    "ثمانين",
+    "تسعين",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "مائة",
+    "مائتين",
+    "ثلاثمائة",
+    "أربعمائة",
+    "خمسمائة",
+    "ستمائة",
+    "سبعمائة",
+    "ثمانمائة",
+    "تسعمائة",
+];
+
+// (value, singular, dual, plural, accusative singular)
+const SCALES: [(u64, &str, &str, &str, &str); 4] = [
+    (
+        1_000_000_000_000,
+        "تريليون",
+        "تريليونين",
+        "تريليونات",
+        "تريليوناً",
+    ),
+    (1_000_000_000, "مليار", "مليارين", "مليارات", "ملياراً"),
+    (1_000_000, "مليون", "مليونين", "ملايين", "مليوناً"),
+    (1_000, "ألف", "ألفين", "آلاف", "ألفاً"),
+];
+
+/// Number in arabic words, counting a noun of the given gender
+pub fn arabic_cardinal(n: u64, gender: Gender) -> String {
+    if n == 0 {
+        return "صفر".to_string();
+    }
+
+    let mut parts = Vec::new();
+    let mut rest = n;
+    for &(value, singular, dual, plural, accusative) in SCALES.iter() {
+        let count = rest / value;
+        rest %= value;
+        match (count, count % 100) {
+            (0, _) => {}
+            (1, _) => parts.push(singular.to_string()),
+            (2, _) => parts.push(dual.to_string()),
+            (_, 3..=10) => parts.push(format!(
+                "{} {}",
+                below_thousand(count, Gender::Masculine),
+                plural
+            )),
+            (_, 11..=99) => parts.push(format!(
+                "{} {}",
+                below_thousand(count, Gender::Masculine),
+                accusative
+            )),
+            _ => parts.push(format!(
+                "{} {}",
+                below_thousand(count, Gender::Masculine),
+                singular
+            )),
+        }
+    }
+    if rest > 0 {
+        parts.push(below_thousand(rest, gender));
+    }
+    parts.join(" و")
+}
+
+fn below_thousand(n: u64, gender: Gender) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let units = match gender {
+        Gender::Masculine => UNITS_MASCULINE,
+        Gender::Feminine => UNITS_FEMININE,
+    };
+    // the ten of 11 to 19 agrees with the counted noun, the other units disagree
+    let (eleven, twelve, ten) = match gender {
+        Gender::Masculine => ("أحد", "اثني", "عشر"),
+        Gender::Feminine => ("إحدى", "اثنتي", "عشرة"),
+    };
+
+    let rest = match rest {
+        0 => String::new(),
+        1..=10 => units[rest].to_string(),
+        11 => format!("{} {}", eleven, ten),
+        12 => format!("{} {}", twelve, ten),
+        13..=19 => format!("{} {}", units[rest - 10], ten),
+        _ if rest % 10 == 0 => TENS[rest / 10].to_string(),
+        _ => {
+            let unit = match (rest % 10, gender) {
+                (1, Gender::Feminine) => "إحدى",
+                (unit, _) => units[unit],
+            };
+            format!("{} و{}", unit, TENS[rest / 10])
+        }
+    };
+
+    match (hundreds, rest.is_empty()) {
+        (0, _) => rest,
+        (_, true) => HUNDREDS[hundreds].to_string(),
+        (_, false) => format!("{} و{}", HUNDREDS[hundreds], rest),
+    }
+}
+
+// ordinals from 1 to 10 for a masculine noun, then a feminine one
+const ORDINALS_MASCULINE: [&str; 11] = [
+    "",
+    "الأول",
+    "الثاني",
+    "الثالث",
+    "الرابع",
+    "الخامس",
+    "السادس",
+    "السابع",
+    "الثامن",
+    "التاسع",
+    "العاشر",
+];
+const ORDINALS_FEMININE: [&str; 11] = [
+    "",
+    "الأولى",
+    "الثانية",
+    "الثالثة",
+    "الرابعة",
+    "الخامسة",
+    "السادسة",
+    "السابعة",
+    "الثامنة",
+    "التاسعة",
+    "العاشرة",
+];
+
+const ORDINAL_TENS: [&str; 10] = [
+    "",
+    "",
+    "العشرون",
+    "الثلاثون",
+    "الأربعون",
+    "الخمسون",
+    "الستون",
+    "السبعون",
+    "الثمانون",
+    "التسعون",
+];
+
+/// Arabic ordinal with the definite article, from 1 to 99 (`None` otherwise)
+pub fn arabic_ordinal(n: u64, gender: Gender) -> Option<String> {
+    let n = match n {
+        1..=99 => n as usize,
+        _ => return None,
+    };
+    let (ordinals, one, ten) = match gender {
+        Gender::Masculine => (ORDINALS_MASCULINE, "الحادي", "عشر"),
+        Gender::Feminine => (ORDINALS_FEMININE, "الحادية", "عشرة"),
+    };
+    let ordinal = match n {
+        1..=10 => ordinals[n].to_string(),
+        11 => format!("{} {}", one, ten),
+        12..=19 => format!("{} {}", ordinals[n - 10], ten),
+        20..=99 if n % 10 == 0 => ORDINAL_TENS[n / 10].to_string(),
+        _ => {
+            let unit = if n % 10 == 1 { one } else { ordinals[n % 10] };
+            format!("{} و{}", unit, ORDINAL_TENS[n / 10])
+        }
+    };
+    Some(ordinal)
+}
+
+const ENGLISH_ORDINALS: [&str; 20] = [
+    "",
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+
+// (cardinal, ordinal) of the tens from 20
+const ENGLISH_TENS: [(&str, &str); 10] = [
+    ("", ""),
+    ("", ""),
+    ("twenty", "twentieth"),
+    ("thirty", "thirtieth"),
+    ("forty", "fortieth"),
+    ("fifty", "fiftieth"),
+    ("sixty", "sixtieth"),
+    ("seventy", "seventieth"),
+    ("eighty", "eightieth"),
+    ("ninety", "ninetieth"),
+];
+
+/// English ordinal, from 1 to 99 (`None` otherwise)
+pub fn english_ordinal(n: u64) -> Option<String> {
+    let n = match n {
+        1..=99 => n as usize,
+        _ => return None,
+    };
+    let ordinal = match n {
+        1..=19 => ENGLISH_ORDINALS[n].to_string(),
+        20..=99 if n % 10 == 0 => ENGLISH_TENS[n / 10].1.to_string(),
+        _ => format!("{}-{}", ENGLISH_TENS[n / 10].0, ENGLISH_ORDINALS[n % 10]),
+    };
+    Some(ordinal)
+}
+
+impl HijriDate {
+    /// The date in arabic words, example
+    /// `الأول من شهر رمضان لعام ألف وأربعمائة وخمسة وأربعين هجرية`
+    pub fn to_arabic_words(&self) -> String {
+        let era = match self.era() {
+            Era::BH => "قبل الهجرة",
+            _ => "هجرية",
+        };
+        format!(
+            "{} من شهر {} لعام {} {}",
+            self.day_arabic_words(),
+            self.month_name(),
+            self.year_arabic_words(),
+            era
+        )
+    }
+
+    /// The date in english words, example `the first of Ramadan, 1445 AH`
+    pub fn to_english_words(&self) -> String {
+        format!(
+            "the {} of {}, {} {}",
+            self.day_english_words(),
+            self.month_enum().name(Locale::English),
+            self.year_of_era(),
+            self.era().suffix(Locale::English)
+        )
+    }
+
+    pub(crate) fn day_arabic_words(&self) -> String {
+        // days are between 1 and 30
+        arabic_ordinal(self.day() as u64, Gender::Masculine).unwrap()
+    }
+
+    pub(crate) fn day_english_words(&self) -> String {
+        english_ordinal(self.day() as u64).unwrap()
+    }
+
+    // counts the masculine عام, the year of its era
+    pub(crate) fn year_arabic_words(&self) -> String {
+        arabic_cardinal(self.year_of_era() as u64, Gender::Masculine)
+    }
+}
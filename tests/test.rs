@@ -1,4 +1,14 @@
-use hijri_date::{Duration, HijriDate};
+#![allow(clippy::zero_prefixed_literal)]
+
+use hijri_date::words::{arabic_cardinal, arabic_ordinal, english_ordinal, Gender};
+#[cfg(feature = "chrono")]
+use hijri_date::Duration;
+use hijri_date::{compact_hijri, hijri};
+use hijri_date::{
+    CompactHijriDate, DateError, DateFormat, DayNumber, Era, FormatStyle, HijriDate, HijriInterval,
+    HijriMonth, HijriPeriod, HijriWeekday, Locale, NumberingSystem, SolarAlgorithm, SolarHijriDate,
+    SolarLocale, WeekNumbering,
+};
 
 #[test]
 fn dates() {
@@ -12,18 +22,37 @@ fn dates() {
 }
 #[test]
 fn max_min() {
-    //min value //to be precise 1937,03,14
-    let _hd_g = HijriDate::from_gr(1938, 01, 01).unwrap();
-    //println!("{}-{}-{}",hd_g.year,hd_g.month,hd_g.day);
-    let _hd = HijriDate::from_hijri(1357, 1, 1).unwrap();
-    //assert_eq!(hd,hd_g);
+    let hd_g = HijriDate::from_gr(1937, 3, 14).unwrap();
+    let hd = HijriDate::from_hijri(1356, 1, 1).unwrap();
+    assert_eq!(hd, hd_g);
+    assert_eq!(hd, HijriDate::MIN);
+    #[cfg(feature = "chrono")]
+    assert_eq!("1937-03-14", HijriDate::MIN_GR.to_string());
+    assert!(HijriDate::from_gr(1937, 3, 13).is_err());
+    assert!(HijriDate::from_hijri(1355, 12, 29).is_err());
 
-    //max value //to be precise 2077,11,16
-    let _hd_g = HijriDate::from_gr(2076, 12, 31).unwrap();
-    //println!("{:?}",hd_g);
-    let _hd = HijriDate::from_hijri(1499, 12, 30).unwrap();
+    let hd_g = HijriDate::from_gr(2077, 11, 16).unwrap();
+    let hd = HijriDate::from_hijri(1500, 12, 30).unwrap();
+    assert_eq!(hd, hd_g);
+    assert_eq!(hd, HijriDate::MAX);
+    #[cfg(feature = "chrono")]
+    assert_eq!("2077-11-16", HijriDate::MAX_GR.to_string());
+    assert_eq!(
+        Err("maximum handled gregorian date is 2077-11-16, input date: 2077-11-17".to_string()),
+        HijriDate::from_gr(2077, 11, 17)
+    );
+    assert!(HijriDate::from_hijri(1501, 1, 1).is_err());
+    assert!(HijriDate::from_hijri(i32::MAX, 12, 30).is_err());
+    assert_eq!(
+        Err(DateError::OutOfRange),
+        CompactHijriDate::from_hijri(i32::MAX, 12, 30)
+    );
 
-    //assert_eq!(hd,hd_g);
+    // 1499-12 has 29 days
+    assert_eq!(
+        Err("enter a valid day, 1499-12 has 29 days, Err d = 30".to_string()),
+        HijriDate::from_hijri(1499, 12, 30)
+    );
 }
 
 #[test]
@@ -37,6 +66,7 @@ fn cmp() {
     assert!(hd_1 > hd_2);
 }
 
+#[cfg(feature = "chrono")]
 #[test]
 fn add_sub() {
     let hd_1 = HijriDate::from_hijri(1420, 06, 15).unwrap();
@@ -48,6 +78,7 @@ fn add_sub() {
     assert_eq!(hd_1 + Duration::days(12), hd_2);
 }
 
+#[cfg(feature = "chrono")]
 #[test]
 fn sub_day() {
     let hd_1 = HijriDate::from_hijri(1357, 06, 15).unwrap();
@@ -69,3 +100,1037 @@ fn invalid() {
     //let hd_g = HijriDate::from_gr(2077, 11 ,16);
     let _hd_g = HijriDate::from_gr(1935, 11, 16).unwrap();
 }
+
+#[cfg(feature = "chrono")]
+#[test]
+fn prayer_times() {
+    use hijri_date::prayer_times::{Asr, Location, Method, Params, PrayerTimes};
+
+    let mecca = Location::new(21.4225, 39.8262, 3.0);
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    let times = hd.prayer_times(&mecca, &Params::default()).unwrap();
+    assert_eq!(
+        times,
+        PrayerTimes::from_gr(2024, 3, 11, &mecca, &Params::default()).unwrap()
+    );
+    assert_eq!("05:17", times.fajr.format("%H:%M").to_string());
+    assert_eq!("12:31", times.dhuhr.format("%H:%M").to_string());
+    assert_eq!("15:54", times.asr.format("%H:%M").to_string());
+    assert_eq!("18:29", times.maghrib.format("%H:%M").to_string());
+    // umm al-qura isha is two hours after maghrib in ramadan
+    assert_eq!("20:29", times.isha.format("%H:%M").to_string());
+
+    let hanafi = Params {
+        asr: Asr::Hanafi,
+        ..Params::new(Method::MuslimWorldLeague)
+    };
+    assert!(hd.prayer_times(&mecca, &hanafi).unwrap().asr > times.asr);
+
+    let svalbard = Location::new(78.0, 15.0, 2.0);
+    assert!(PrayerTimes::from_gr(2024, 6, 21, &svalbard, &Params::default()).is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn imsakiyah() {
+    use hijri_date::imsakiyah::Imsakiyah;
+    use hijri_date::prayer_times::{Location, Params};
+
+    let mecca = Location::new(21.4225, 39.8262, 3.0);
+    let imsakiyah = Imsakiyah::new(1445, &mecca, &Params::default()).unwrap();
+    assert_eq!(30, imsakiyah.days.len());
+
+    let csv = imsakiyah.to_csv(Locale::English);
+    let mut lines = csv.lines();
+    assert_eq!(
+        Some("Ramadan,Day,Date,Imsak,Fajr,Sunrise,Dhuhr,Asr,Maghrib,Isha"),
+        lines.next()
+    );
+    assert_eq!(
+        Some("1,Monday,2024-03-11,05:07,05:17,06:33,12:31,15:54,18:29,20:29"),
+        lines.next()
+    );
+    assert_eq!(29, lines.count());
+
+    assert!(imsakiyah
+        .to_markdown(Locale::Arabic)
+        .starts_with("| رمضان | اليوم |"));
+    assert!(imsakiyah
+        .to_html(Locale::Arabic)
+        .contains("<html lang=\"ar\" dir=\"rtl\">"));
+}
+
+#[test]
+fn month_weekday_enums() {
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    assert_eq!(HijriMonth::Ramadan, hd.month_enum());
+    assert_eq!(HijriWeekday::Monday, hd.weekday());
+    assert_eq!(hd.month_name(), hd.month_enum().name(Locale::Arabic));
+    assert_eq!(hd.day_name_en(), hd.weekday().name(Locale::English));
+
+    assert_eq!(Some(HijriMonth::DhulHijjah), HijriMonth::from_number(12));
+    assert_eq!(None, HijriMonth::from_number(0));
+    assert_eq!(HijriMonth::Muharram, HijriMonth::DhulHijjah.succ());
+    assert_eq!(HijriWeekday::Saturday, HijriWeekday::Sunday.pred());
+    assert_eq!(7, HijriWeekday::Saturday.number());
+
+    assert_eq!(Ok(HijriMonth::Shaban), "Sha'ban".parse());
+    assert_eq!(Ok(HijriMonth::JumadaAlUla), "جمادى الأولى".parse());
+    assert_eq!(Ok(HijriMonth::DhulHijjah), "dhu al-hijjah".parse());
+    assert_eq!(Ok(HijriWeekday::Friday), "الجمعة".parse());
+    assert!("Ramadhan".parse::<HijriMonth>().is_err());
+}
+
+#[test]
+fn day_numbers() {
+    let hd = HijriDate::from_hijri(1421, 4, 29).unwrap();
+    assert_eq!(2_451_757, hd.to_jdn());
+    assert_eq!(51_756, hd.to_mjd());
+    assert_eq!(730_332, hd.to_rata_die());
+    assert_eq!(Ok(hd.clone()), HijriDate::from_jdn(2_451_757));
+    assert_eq!(Ok(hd.clone()), HijriDate::from_mjd(51_756));
+    assert_eq!(Ok(hd.clone()), HijriDate::from_rata_die(730_332));
+
+    let dn = hd.day_number();
+    assert_eq!(Some((2000, 7, 31)), dn.to_gregorian());
+    assert_eq!(None, DayNumber::from_jdn(i64::MAX).to_gregorian());
+    assert_eq!(None, DayNumber::from_jdn(i64::MIN).to_julian());
+    assert_eq!(Some(dn), DayNumber::from_gregorian(2000, 7, 31));
+    assert_eq!(None, DayNumber::from_gregorian(2001, 2, 29));
+    assert_eq!(0, DayNumber::from_gregorian(1858, 11, 17).unwrap().mjd());
+    assert_eq!(1, DayNumber::from_gregorian(1, 1, 1).unwrap().rata_die());
+    #[cfg(feature = "chrono")]
+    assert_eq!(Duration::days(1), (hd.clone() + Duration::days(1)) - hd);
+
+    assert!(HijriDate::from_jdn(HijriDate::MIN.to_jdn() - 1).is_err());
+    assert!(HijriDate::from_jdn(HijriDate::MAX.to_jdn() + 1).is_err());
+}
+
+#[test]
+fn periods() {
+    let hd_1 = HijriDate::from_hijri(1442, 7, 20).unwrap();
+    let hd_2 = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    assert_eq!(HijriPeriod::new(3, 1, 10), hd_1.until(&hd_2));
+    assert_eq!(HijriPeriod::new(-3, -1, -10), hd_2.until(&hd_1));
+    assert_eq!(hd_1.until(&hd_2), hd_2.since(&hd_1));
+    assert_eq!(37, hd_1.months_between(&hd_2));
+    assert_eq!(-3, hd_2.years_between(&hd_1));
+    assert_eq!("P3Y1M10D", hd_1.until(&hd_2).to_string());
+    assert_eq!(HijriPeriod::default(), hd_1.until(&hd_1));
+
+    // 1445-01 has 29 days, the 30th is clamped when counting months
+    let hd_1 = HijriDate::from_hijri(1444, 12, 30).unwrap();
+    let hd_2 = HijriDate::from_hijri(1445, 1, 29).unwrap();
+    assert_eq!(HijriPeriod::new(0, 1, 0), hd_1.until(&hd_2));
+    let hd_2 = HijriDate::from_hijri(1445, 1, 28).unwrap();
+    assert_eq!(HijriPeriod::new(0, 0, 28), hd_1.until(&hd_2));
+}
+
+#[test]
+fn recurrences() {
+    use hijri_date::recurrence::{MissingDay, Recurrence};
+
+    let start = HijriDate::from_hijri(1445, 1, 1).unwrap();
+    let firsts: Vec<_> = Recurrence::monthly()
+        .by_day(&[1])
+        .count(13)
+        .occurrences(&start)
+        .map(|d| (d.year(), d.month(), d.day()))
+        .collect();
+    assert_eq!(13, firsts.len());
+    assert_eq!((1446, 1, 1), firsts[12]);
+
+    let last_days: Vec<_> = Recurrence::monthly()
+        .by_day(&[-1])
+        .count(2)
+        .occurrences(&start)
+        .map(|d| d.day())
+        .collect();
+    assert_eq!(vec![29, 30], last_days);
+
+    // birthday on the 30th of Dhu al-Hijjah, a 29 days month in 1446 and 1447
+    let birth = HijriDate::from_hijri(1445, 12, 30).unwrap();
+    let birthdays = |missing_day| {
+        Recurrence::yearly()
+            .missing_day(missing_day)
+            .count(2)
+            .occurrences(&birth)
+            .map(|d| d.format("%Y-%m-%d"))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        vec!["1445-12-30", "1448-12-30"],
+        birthdays(MissingDay::Skip)
+    );
+    assert_eq!(
+        vec!["1445-12-30", "1446-12-29"],
+        birthdays(MissingDay::LastDay)
+    );
+    assert_eq!(
+        vec!["1445-12-30", "1447-1-1"],
+        birthdays(MissingDay::NextMonth)
+    );
+
+    let fridays = Recurrence::weekly()
+        .by_weekday(&[HijriWeekday::Friday])
+        .by_month(&[HijriMonth::Ramadan])
+        .until(HijriDate::from_hijri(1445, 12, 1).unwrap());
+    let fridays: Vec<_> = fridays.occurrences(&start).collect();
+    assert_eq!(4, fridays.len());
+    assert!(fridays
+        .iter()
+        .all(|d| d.weekday() == HijriWeekday::Friday && d.month() == 9));
+
+    let yearly = Recurrence::yearly();
+    let end = yearly.occurrences(&HijriDate::from_hijri(1499, 1, 1).unwrap());
+    assert_eq!(2, end.count());
+    assert_eq!(1, yearly.interval(usize::MAX).occurrences(&start).count());
+
+    // -30 is missing from Muharram 1445, a 29 days month
+    let thirtieths_from_end = |missing_day| {
+        Recurrence::monthly()
+            .by_day(&[-30])
+            .missing_day(missing_day)
+            .count(1)
+            .occurrences(&start)
+            .map(|d| d.format("%Y-%m-%d"))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec!["1445-2-1"], thirtieths_from_end(MissingDay::Skip));
+    assert_eq!(vec!["1445-1-1"], thirtieths_from_end(MissingDay::LastDay));
+    let before = HijriDate::from_hijri(1444, 12, 1).unwrap();
+    let previous = Recurrence::monthly()
+        .by_day(&[-30])
+        .missing_day(MissingDay::NextMonth)
+        .occurrences(&before)
+        .nth(1)
+        .unwrap();
+    assert_eq!(start.day_number() - 1, previous.day_number());
+}
+
+#[test]
+fn navigation() {
+    let hd = HijriDate::from_hijri(1445, 7, 10).unwrap();
+    assert_eq!("1445-7-1", hd.first_day_of_month().format("%Y-%m-%d"));
+    assert_eq!("1445-7-29", hd.last_day_of_month().format("%Y-%m-%d"));
+    assert_eq!("1445-1-1", hd.first_day_of_year().format("%Y-%m-%d"));
+    assert_eq!("1445-12-30", hd.last_day_of_year().format("%Y-%m-%d"));
+
+    // 1445-7-10 is a Monday
+    assert_eq!(HijriWeekday::Monday, hd.weekday());
+    let next = hd.next_weekday(HijriWeekday::Monday).unwrap();
+    assert_eq!(hd.to_jdn() + 7, next.to_jdn());
+    let prev = hd.prev_weekday(HijriWeekday::Friday).unwrap();
+    assert_eq!(hd.to_jdn() - 3, prev.to_jdn());
+
+    let third_monday = hd.nth_weekday_of_month(3, HijriWeekday::Monday).unwrap();
+    assert_eq!("1445-7-17", third_monday.format("%Y-%m-%d"));
+    let last_friday = hd.nth_weekday_of_month(-1, HijriWeekday::Friday).unwrap();
+    assert_eq!("1445-7-28", last_friday.format("%Y-%m-%d"));
+    assert_eq!(None, hd.nth_weekday_of_month(5, HijriWeekday::Monday));
+    assert_eq!(None, hd.nth_weekday_of_month(0, HijriWeekday::Monday));
+    assert_eq!(
+        None,
+        hd.nth_weekday_of_month(i32::MAX, HijriWeekday::Monday)
+    );
+    assert_eq!(
+        None,
+        hd.nth_weekday_of_month(i32::MIN, HijriWeekday::Monday)
+    );
+
+    assert_eq!(None, HijriDate::MAX.next_weekday(HijriWeekday::Monday));
+}
+
+#[test]
+fn ordinals_and_weeks() {
+    let hd = HijriDate::from_hijri(1445, 12, 30).unwrap();
+    assert_eq!(354, hd.ordinal());
+    assert_eq!(354, hd.year_len());
+    assert!(!hd.is_long_year());
+    assert!(HijriDate::from_hijri(1443, 1, 1).unwrap().is_long_year());
+    assert_eq!(Ok(hd.clone()), HijriDate::from_ordinal(1445, 354));
+    assert!(HijriDate::from_ordinal(1445, 355).is_err());
+    assert_eq!(Ok(1), HijriDate::from_ordinal(1445, 1).map(|d| d.day()));
+
+    // 1 Muharram 1446 is a Sunday, so 1445-12-30 (Saturday) is in week 1 of 1446
+    assert_eq!((1446, 1), hd.week_of_year());
+    assert_eq!(
+        (1445, 51),
+        WeekNumbering::new(HijriWeekday::Sunday).week_of_year(&hd)
+    );
+    assert_eq!(
+        Ok(hd),
+        HijriDate::from_week(1446, 1, HijriWeekday::Saturday)
+    );
+
+    let weeks = WeekNumbering::default();
+    let mut date = HijriDate::from_hijri(1440, 1, 1).unwrap();
+    while date.year() < 1450 {
+        let (year, week) = weeks.week_of_year(&date);
+        assert!(week <= weeks.weeks_in_year(year).unwrap());
+        assert_eq!(
+            Ok(date.clone()),
+            weeks.from_week(year, week, date.weekday())
+        );
+        date = HijriDate::from_day_number(date.day_number() + 1).unwrap();
+    }
+}
+
+#[test]
+fn intervals() {
+    let date = |y, m, d| HijriDate::from_hijri(y, m, d).unwrap();
+
+    let leave_1 = HijriInterval::closed(date(1445, 8, 20), date(1445, 9, 10)).unwrap();
+    let leave_2 = HijriInterval::half_open(date(1445, 9, 10), date(1445, 10, 5)).unwrap();
+    assert_eq!(20, leave_1.len_days());
+    assert_eq!(25, leave_2.len_days());
+    assert!(leave_1.contains(&date(1445, 9, 10)));
+    assert!(!leave_2.contains(&date(1445, 10, 5)));
+
+    assert!(leave_1.overlaps(&leave_2));
+    let common = leave_1.intersection(&leave_2).unwrap();
+    assert_eq!(
+        (date(1445, 9, 10), 1),
+        (common.start().clone(), common.len_days())
+    );
+    let all = leave_1.union(&leave_2).unwrap();
+    assert_eq!(44, all.len_days());
+    assert!(all.is_closed());
+
+    let after = HijriInterval::half_open(date(1445, 10, 5), date(1445, 10, 6)).unwrap();
+    assert!(!leave_2.overlaps(&after));
+    assert_eq!(Some(26), leave_2.union(&after).map(|i| i.len_days()));
+    assert_eq!(None, leave_1.union(&after));
+    assert!(HijriInterval::half_open(date(1445, 1, 1), date(1445, 1, 1))
+        .unwrap()
+        .is_empty());
+    assert!(HijriInterval::closed(date(1445, 1, 2), date(1445, 1, 1)).is_err());
+
+    let months: Vec<_> = all
+        .split_by_hijri_month()
+        .iter()
+        .map(|i| (i.start().month(), i.len_days()))
+        .collect();
+    assert_eq!(vec![(8, 10), (9, 30), (10, 4)], months);
+
+    let years = HijriInterval::closed(date(1444, 6, 1), HijriDate::MAX)
+        .unwrap()
+        .split_by_hijri_year();
+    assert_eq!(57, years.len());
+    assert_eq!(&HijriDate::MAX, years[56].end());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn buckets() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use hijri_date::{bucket, HijriYearMonth};
+    use HijriMonth::*;
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let sales = vec![
+        (date(2024, 6, 8), 50),
+        (date(2024, 3, 11), 120),
+        (date(2024, 4, 9), 80),
+        (date(2024, 4, 10), 30),
+    ];
+    let months: Vec<_> = bucket::by_month(sales.clone())
+        .unwrap()
+        .into_iter()
+        .map(|(month, values)| (month, values.iter().sum::<i32>()))
+        .collect();
+    let ym = |month| HijriYearMonth::new(1445, month);
+    assert_eq!(
+        vec![
+            (ym(Ramadan), 200),
+            (ym(Shawwal), 30),
+            (ym(DhulQadah), 0),
+            (ym(DhulHijjah), 50)
+        ],
+        months
+    );
+    assert_eq!(ym(Shawwal), ym(Ramadan).succ());
+    assert_eq!(HijriYearMonth::new(1446, Muharram), ym(DhulHijjah).succ());
+    assert_eq!("1445-09", ym(Ramadan).to_string());
+    assert_eq!(
+        ym(Ramadan),
+        HijriDate::from_gr(2024, 4, 9).unwrap().year_month()
+    );
+
+    let years = bucket::by_year(vec![date(2023, 7, 18), date(2024, 7, 8)]).unwrap();
+    assert_eq!(
+        vec![1444, 1445, 1446],
+        years.iter().map(|b| b.0).collect::<Vec<_>>()
+    );
+    assert!(years[1].1.is_empty());
+
+    let numbering = WeekNumbering::default();
+    let times = vec![
+        Utc.with_ymd_and_hms(2024, 3, 16, 10, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2024, 3, 29, 23, 0, 0).unwrap(),
+    ];
+    let weeks = bucket::by_week(times, &numbering).unwrap();
+    assert_eq!(
+        vec![(1445, 36), (1445, 37)],
+        weeks.iter().map(|b| b.0).collect::<Vec<_>>()
+    );
+    let hd = HijriDate::from_gr(2024, 3, 29).unwrap();
+    assert_eq!(numbering.week_of_year(&hd), (1445, 37));
+
+    assert!(bucket::by_month(vec![date(2077, 11, 17)]).is_err());
+    assert!(bucket::by_month(Vec::<NaiveDate>::new())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn humanize() {
+    let date = |y, m, d| HijriDate::from_hijri(y, m, d).unwrap();
+    let today = date(1445, 9, 10);
+    let en = |y, m, d| date(y, m, d).humanize(&today, Locale::English);
+    let ar = |y, m, d| date(y, m, d).humanize(&today, Locale::Arabic);
+
+    assert_eq!("today", en(1445, 9, 10));
+    assert_eq!("tomorrow", en(1445, 9, 11));
+    assert_eq!("yesterday", en(1445, 9, 9));
+    assert_eq!("in 12 days", en(1445, 9, 22));
+    assert_eq!("2 weeks ago", en(1445, 8, 25));
+    assert_eq!("in 1 month", en(1445, 10, 15));
+    assert_eq!("25 years ago", en(1420, 6, 15));
+
+    assert_eq!("اليوم", ar(1445, 9, 10));
+    assert_eq!("غداً", ar(1445, 9, 11));
+    assert_eq!("أمس", ar(1445, 9, 9));
+    assert_eq!("منذ يومين", ar(1445, 9, 8));
+    assert_eq!("بعد 3 أيام", ar(1445, 9, 13));
+    assert_eq!("بعد 10 أيام", ar(1445, 9, 20));
+    assert_eq!("منذ 11 شهراً", ar(1444, 10, 1));
+    assert_eq!("بعد شهرين", ar(1445, 11, 10));
+    assert_eq!("منذ سنة", ar(1444, 9, 10));
+    assert_eq!("بعد 3 سنوات", ar(1448, 9, 10));
+    assert_eq!("منذ 25 سنة", ar(1420, 6, 15));
+
+    assert_eq!(
+        Some(date(1446, 9, 1)),
+        today.next_occurrence(HijriMonth::Ramadan, 1)
+    );
+    assert_eq!(Some(0), today.countdown(HijriMonth::Ramadan, 10));
+    assert_eq!(Some(20), today.countdown(HijriMonth::Ramadan, 30));
+    // Ramadan 1446 has 29 days
+    assert_eq!(
+        Some(date(1447, 9, 30)),
+        date(1445, 10, 1).next_occurrence(HijriMonth::Ramadan, 30)
+    );
+    assert_eq!(None, HijriDate::MAX.countdown(HijriMonth::Muharram, 1));
+}
+
+#[test]
+fn words() {
+    use Gender::*;
+
+    let cardinals = [
+        (1, Masculine, "واحد"),
+        (2, Feminine, "اثنتين"),
+        (3, Masculine, "ثلاثة"),
+        (3, Feminine, "ثلاث"),
+        (11, Masculine, "أحد عشر"),
+        (11, Feminine, "إحدى عشرة"),
+        (12, Masculine, "اثني عشر"),
+        (15, Masculine, "خمسة عشر"),
+        (15, Feminine, "خمس عشرة"),
+        (21, Feminine, "إحدى وعشرين"),
+        (100, Masculine, "مائة"),
+        (200, Masculine, "مائتين"),
+        (1000, Masculine, "ألف"),
+        (2000, Masculine, "ألفين"),
+        (3000, Masculine, "ثلاثة آلاف"),
+        (11000, Masculine, "أحد عشر ألفاً"),
+        (100_000, Masculine, "مائة ألف"),
+        (1356, Masculine, "ألف وثلاثمائة وستة وخمسين"),
+        (1500, Masculine, "ألف وخمسمائة"),
+        (2_000_001, Masculine, "مليونين وواحد"),
+    ];
+    for (n, gender, words) in cardinals.iter() {
+        assert_eq!(*words, arabic_cardinal(*n, *gender), "{}", n);
+    }
+    assert_eq!(
+        Some("الثاني عشر".to_string()),
+        arabic_ordinal(12, Masculine)
+    );
+    assert_eq!(
+        Some("الحادية عشرة".to_string()),
+        arabic_ordinal(11, Feminine)
+    );
+    assert_eq!(Some("الثلاثون".to_string()), arabic_ordinal(30, Masculine));
+    assert_eq!(None, arabic_ordinal(0, Masculine));
+    assert_eq!(Some("twelfth".to_string()), english_ordinal(12));
+    assert_eq!(Some("thirtieth".to_string()), english_ordinal(30));
+    assert_eq!(None, english_ordinal(100));
+
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    assert_eq!(
+        "الأول من شهر رمضان لعام ألف وأربعمائة وخمسة وأربعين هجرية",
+        hd.to_arabic_words()
+    );
+    assert_eq!("the first of Ramadan, 1445 AH", hd.to_english_words());
+    let hd = HijriDate::from_hijri(1446, 12, 29).unwrap();
+    assert_eq!(
+        "التاسع والعشرون من ذو الحجة ألف وأربعمائة وستة وأربعين",
+        hd.format("%wd من %M %wY")
+    );
+    assert_eq!(
+        "the twenty-ninth of Dhu al-Hijjah",
+        hd.format("the %ed of Dhu al-Hijjah")
+    );
+}
+
+#[test]
+fn numbering_systems() {
+    use NumberingSystem::*;
+
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    let f = "%Y/%m/%d %gd-%gm-%gY";
+    assert_eq!("1445/9/1 11-3-2024", hd.format_with(f, Latin));
+    assert_eq!("١٤٤٥/٩/١ ١١-٣-٢٠٢٤", hd.format_with(f, ArabicIndic));
+    assert_eq!("۱۴۴۵/۹/۱ ۱۱-۳-۲۰۲۴", hd.format_with(f, ExtendedArabicIndic));
+    // only the fields are converted
+    assert_eq!("٣٠ 2", hd.format_with("%l 2", ArabicIndic));
+
+    assert_eq!(
+        "1445-09-01 1445",
+        NumberingSystem::to_latin("۱۴۴۵-۰۹-۰۱ ١٤٤٥")
+    );
+    for s in ["1445-9-1", "١٤٤٥/٠٩/٠١", "۱۴۴۵.۹.۱", " 1445 / ٩ / ۱ "].iter() {
+        assert_eq!(Ok(hd.clone()), s.parse::<HijriDate>(), "{}", s);
+    }
+    assert!("1445-9".parse::<HijriDate>().is_err());
+    assert!("1445-9-x".parse::<HijriDate>().is_err());
+    assert!("1445-13-1".parse::<HijriDate>().is_err());
+
+    assert_eq!(Ok(HijriMonth::Ramadan), "٩".parse());
+    assert_eq!(Ok(HijriWeekday::Friday), "۶".parse());
+}
+
+#[test]
+fn format_styles() {
+    use FormatStyle::*;
+
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    let en = |style| hd.format_style(style, Locale::English);
+    let ar = |style| hd.format_style(style, Locale::Arabic);
+    assert_eq!("1/9/1445", en(Short));
+    assert_eq!("1 Ram. 1445 AH", en(Medium));
+    assert_eq!("1 Ramadan 1445 AH", en(Long));
+    assert_eq!("Monday, 1 Ramadan 1445 AH", en(Full));
+    assert_eq!("1/9/1445", ar(Short));
+    assert_eq!("1 رمضان 1445 هـ", ar(Medium));
+    assert_eq!("الاثنين، 1 رمضان 1445 هـ", ar(Full));
+
+    let dual = DateFormat::new(Medium, Locale::English).gregorian(true);
+    assert_eq!("1 Ram. 1445 AH (11 Mar 2024 CE)", dual.format(&hd));
+    let short = DateFormat::new(Short, Locale::Arabic)
+        .gregorian(true)
+        .numbering(NumberingSystem::ArabicIndic);
+    assert_eq!("١/٩/١٤٤٥ الموافق ١١/٣/٢٠٢٤", short.format(&hd));
+    let no_era = DateFormat::new(Long, Locale::English).era(false);
+    assert_eq!("1 Ramadan 1445", hd.styled(no_era).to_string());
+
+    assert_eq!(
+        "الاثنين، 1 رمضان 1445 هـ الموافق 11 مارس 2024 م",
+        hd.to_string()
+    );
+}
+
+#[test]
+fn bidi() {
+    use hijri_date::bidi::{ALM, LRI, PDI, RLI};
+
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    let isolated = hd.format_isolated("%Y %M %D", NumberingSystem::Latin);
+    assert_eq!(
+        format!("1445 {}رمضان{} {}الاثنين{}", RLI, PDI, RLI, PDI),
+        isolated
+    );
+    assert_eq!(
+        "1445 رمضان الاثنين",
+        hd.format_with("%Y %M %D", NumberingSystem::Latin)
+    );
+
+    let long = DateFormat::new(FormatStyle::Long, Locale::English).bidi(true);
+    assert_eq!(format!("{}1 Ramadan 1445 AH{}", LRI, PDI), long.format(&hd));
+    let short = DateFormat::new(FormatStyle::Short, Locale::Arabic);
+    assert_eq!("1/9/1445", hd.styled(short).to_string());
+    assert_eq!(
+        format!("{}1{}/9{}/1445{}", RLI, ALM, ALM, PDI),
+        format!("{:#}", hd.styled(short))
+    );
+
+    let embedded = format!("Due on {:#}.", hd);
+    assert!(embedded.starts_with(&format!("Due on {}الاثنين، ", RLI)));
+    assert!(embedded.ends_with(&format!("2024 م{}.", PDI)));
+}
+
+#[test]
+fn eras_and_extended_dates() {
+    let hijra = HijriDate::from_hijri_extended(1, 1, 1).unwrap();
+    assert_eq!(1_948_440, hijra.to_jdn());
+    assert_eq!("622-7-19 Friday", hijra.format("%gY-%gm-%gd %gD"));
+    assert!(hijra.is_tabular());
+    assert!(HijriDate::from_hijri(1, 1, 1).is_err());
+    assert!(HijriDate::from_hijri_extended(1, 2, 30).is_err());
+
+    let bh = HijriDate::from_hijri_extended(-4, 9, 1).unwrap();
+    assert_eq!((Era::BH, 5), (bh.era(), bh.year_of_era()));
+    assert_eq!("-4 5 BH", bh.format("%Y %y %E"));
+    assert_eq!(
+        "1 Ramadan 5 BH",
+        bh.format_style(FormatStyle::Long, Locale::English)
+    );
+    assert_eq!(
+        "1/9/5 BH",
+        bh.format_style(FormatStyle::Short, Locale::English)
+    );
+    assert_eq!(
+        "1 رمضان 5 ق.هـ",
+        bh.format_style(FormatStyle::Long, Locale::Arabic)
+    );
+    assert_eq!(
+        "الأول من شهر رمضان لعام خمسة قبل الهجرة",
+        bh.to_arabic_words()
+    );
+    assert_eq!("the first of Ramadan, 5 BH", bh.to_english_words());
+
+    assert_eq!(Ok(bh), "5-9-1 BH".parse());
+    assert_eq!(HijriDate::from_hijri(1445, 9, 1), "١٤٤٥/٩/١ هـ".parse());
+    assert!("1300-1-1 A.H.".parse::<HijriDate>().unwrap().is_tabular());
+    assert!("1300-1-1".parse::<HijriDate>().is_err());
+    assert!("1-1-1 CE".parse::<HijriDate>().is_err());
+    assert_eq!(Ok(Era::BH), "B.H.".parse());
+    assert_eq!(Ok(Era::BCE), "ق.م".parse());
+    assert_eq!(Some(1 - 44), Era::BCE.astronomical_year(44));
+    assert_eq!(None, Era::BH.astronomical_year(u32::MAX));
+    assert!("2147483648-1-1 BH".parse::<HijriDate>().is_err());
+    assert!("4294967295-1-1 BH".parse::<HijriDate>().is_err());
+    assert!("4294967295-1-1".parse::<HijriDate>().is_err());
+
+    let bce = HijriDate::from_gr_extended(0, 1, 1).unwrap();
+    assert_eq!((Era::BCE, 1), (bce.era_gr(), bce.year_of_era_gr()));
+    assert_eq!("1 BCE", bce.format("%gy %gE"));
+
+    // extended dates go on past the table, strict ones stop
+    let last = HijriDate::from_hijri_extended(1500, 12, 30).unwrap();
+    assert_eq!(HijriDate::MAX, last);
+    #[cfg(feature = "chrono")]
+    {
+        let next = last.clone() + Duration::days(1);
+        assert_eq!("1501-1-1", next.format("%Y-%m-%d"));
+        assert!(next.is_tabular());
+    }
+    assert!(last.next_weekday(HijriWeekday::Friday).is_some());
+    assert!(HijriDate::MAX.next_weekday(HijriWeekday::Friday).is_none());
+    let before = HijriDate::from_hijri_extended(1355, 1, 1).unwrap();
+    assert_eq!(
+        HijriPeriod::new(1, 0, 0),
+        before.until(&HijriDate::from_hijri(1356, 1, 1).unwrap())
+    );
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+        HijriDate::MIN,
+        before.last_day_of_year() + Duration::days(1)
+    );
+    for jdn in [i64::MIN, i64::MAX] {
+        assert!(HijriDate::from_day_number_extended(DayNumber::from_jdn(jdn)).is_err());
+    }
+    let far = HijriDate::from_hijri_extended(1600, 1, 1).unwrap();
+    let start = HijriDate::from_hijri(1445, 1, 1).unwrap();
+    assert_eq!(HijriPeriod::new(155, 0, 0), start.until(&far));
+    assert_eq!(HijriPeriod::new(-155, 0, 0), far.until(&start));
+    assert_eq!("in 155 years", far.humanize(&start, Locale::English));
+    let first = HijriDate::from_day_number_extended(DayNumber::from_jdn(-94_024_704)).unwrap();
+    let last = HijriDate::from_day_number_extended(DayNumber::from_jdn(97_466_824)).unwrap();
+    let period = first.until(&last);
+    assert_eq!(-period, last.until(&first));
+    assert_eq!(last.year() - first.year(), period.years + 1);
+}
+
+#[test]
+fn julian_calendar() {
+    // the gregorian reform, 4 October 1582 was followed by 15 October 1582
+    let reform = DayNumber::from_julian(1582, 10, 4).unwrap();
+    assert_eq!(DayNumber::from_gregorian(1582, 10, 15), Some(reform + 1));
+    assert_eq!(
+        Some(DayNumber::from_jdn(0)),
+        DayNumber::from_julian(-4712, 1, 1)
+    );
+    assert!(DayNumber::from_julian(1900, 2, 29).is_some());
+    assert!(DayNumber::from_gregorian(1900, 2, 29).is_none());
+    assert!(DayNumber::from_julian(1937, 2, 29).is_none());
+    for jdn in (-100_000..3_000_000).step_by(97) {
+        let (y, m, d) = DayNumber::from_jdn(jdn).to_julian().unwrap();
+        assert_eq!(
+            Some(DayNumber::from_jdn(jdn)),
+            DayNumber::from_julian(y, m, d)
+        );
+    }
+
+    assert_eq!(Ok(HijriDate::MIN), HijriDate::from_julian(1937, 3, 1));
+    assert_eq!((1937, 3, 1), HijriDate::MIN.to_julian());
+    assert!(HijriDate::from_julian(1937, 2, 28).is_err());
+    assert!(HijriDate::from_julian(1937, 2, 29).is_err());
+
+    let hijra = HijriDate::from_julian_extended(622, 7, 16).unwrap();
+    assert_eq!(HijriDate::from_hijri_extended(1, 1, 1), Ok(hijra));
+    // fall of Constantinople, 29 May 1453 (julian)
+    let hd = HijriDate::from_julian_extended(1453, 5, 29).unwrap();
+    assert_eq!("857-5-20", hd.format("%Y-%m-%d"));
+}
+
+#[test]
+fn solar_hijri() {
+    use SolarAlgorithm::{Arithmetic, Astronomical};
+
+    for (year, nowruz) in [(1399, 20), (1400, 21), (1403, 20), (1404, 21)] {
+        for algorithm in [Astronomical, Arithmetic] {
+            let date = SolarHijriDate::new(year, 1, 1, algorithm).unwrap();
+            assert_eq!((year + 621, 3, nowruz), date.to_gregorian());
+        }
+    }
+    let esfand = SolarHijriDate::new(1403, 12, 30, Astronomical).unwrap();
+    assert_eq!((2025, 3, 20), esfand.to_gregorian());
+    assert!(SolarHijriDate::new(1402, 12, 30, Astronomical).is_err());
+    assert!(SolarHijriDate::new(1402, 7, 31, Arithmetic).is_err());
+    assert!(SolarHijriDate::new(2379, 1, 1, Astronomical).is_err());
+    assert!(SolarHijriDate::new(2379, 1, 1, Arithmetic).is_ok());
+
+    let date = SolarHijriDate::from_gr(2024, 10, 7, Astronomical).unwrap();
+    assert_eq!("1403-07-16", date.to_string());
+    assert_eq!(HijriWeekday::Monday, date.weekday());
+    assert_eq!(202, date.day_of_year());
+    assert_eq!(
+        "دوشنبه ۱۶ مهر ۱۴۰۳",
+        date.format_with(
+            "%D %d %M %Y",
+            SolarLocale::Persian,
+            NumberingSystem::ExtendedArabicIndic
+        )
+    );
+    assert_eq!("16 میزان 1403", date.format("%d %M %Y", SolarLocale::Dari));
+    assert_eq!("16 تله 1403", date.format("%d %M %Y", SolarLocale::Pashto));
+    assert_eq!(
+        "Monday 16 Mehr 1403 = 7 October 2024",
+        date.format("%D %d %M %Y = %gd %gM %gY", SolarLocale::English)
+    );
+
+    let hd = date.to_hijri().unwrap();
+    assert_eq!("1446-4-4", hd.format("%Y-%m-%d"));
+    assert_eq!(Ok(date), hd.to_solar(Astronomical));
+    let old = SolarHijriDate::new(1200, 1, 1, Arithmetic).unwrap();
+    assert!(old.to_hijri().is_err());
+    assert!(old.to_hijri_extended().unwrap().is_tabular());
+    let far = DayNumber::from_jdn(1_000_000_000);
+    assert!(SolarHijriDate::from_day_number(far, Astronomical).is_err());
+    let far = DayNumber::from_jdn(i64::MAX);
+    assert!(SolarHijriDate::from_day_number(far, Arithmetic).is_err());
+    assert!(SolarHijriDate::new(i32::MIN, 1, 1, Arithmetic).is_err());
+    assert!(SolarHijriDate::new(i32::MAX, 12, 29, Arithmetic).is_err());
+}
+
+#[test]
+fn date_literals() {
+    const RAMADAN: HijriDate = hijri!(1445 - 09 - 01);
+    assert_eq!(HijriDate::from_hijri(1445, 9, 1), Ok(RAMADAN));
+    assert_eq!(HijriDate::MIN, hijri!(1356 - 01 - 01));
+    assert_eq!(HijriDate::MAX, hijri!(1500 - 12 - 30));
+
+    assert_eq!(
+        HijriDate::from_hijri(1445, 8, 29).ok(),
+        HijriDate::from_hijri_opt(1445, 8, 29)
+    );
+    assert!(HijriDate::from_hijri_opt(1445, 8, 30).is_none());
+    assert!(HijriDate::from_hijri_opt(1355, 12, 29).is_none());
+    assert!(HijriDate::from_hijri_opt(1501, 1, 1).is_none());
+    assert!(HijriDate::from_hijri_opt(-1445, 1, 1).is_none());
+    assert!(HijriDate::from_hijri_opt(1445, 0, 1).is_none());
+    assert!(HijriDate::from_hijri_opt(1445, 1, 0).is_none());
+
+    const EID: CompactHijriDate = compact_hijri!(1445 - 10 - 01);
+    assert_eq!(CompactHijriDate::from_hijri(1445, 10, 1), Ok(EID));
+    assert_eq!(CompactHijriDate::MAX, compact_hijri!(1500 - 12 - 30));
+    assert_eq!(None, CompactHijriDate::from_literal("1445-08-30"));
+}
+
+#[test]
+fn compact_dates() {
+    use std::convert::TryFrom;
+
+    assert_eq!(8, std::mem::size_of::<CompactHijriDate>());
+    assert_eq!(HijriDate::MIN, CompactHijriDate::MIN.into());
+    assert_eq!(HijriDate::MAX, CompactHijriDate::MAX.into());
+
+    let mut date = CompactHijriDate::MIN;
+    loop {
+        let hd = HijriDate::from(date);
+        assert_eq!(CompactHijriDate::try_from(&hd), Ok(date));
+        assert_eq!(
+            (hd.year(), hd.month(), hd.day(), hd.month_len()),
+            (date.year(), date.month(), date.day(), date.month_len())
+        );
+        assert_eq!(hd.weekday(), date.weekday());
+        match date.checked_add_days(1) {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    assert_eq!(date, CompactHijriDate::MAX);
+
+    let fmt = "%D %d %M %Y (%l) = %gD %gd %gM %gY, 100%";
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    let date = CompactHijriDate::try_from(&hd).unwrap();
+    let mut s = String::new();
+    date.write_format(&mut s, fmt).unwrap();
+    assert_eq!(hd.format(fmt), s);
+    assert_eq!("1445-09-01", date.to_string());
+
+    assert_eq!(
+        CompactHijriDate::from_hijri(1445, 8, 30),
+        Err(DateError::InvalidDay(30))
+    );
+    assert_eq!(
+        CompactHijriDate::from_hijri(1445, 13, 1),
+        Err(DateError::InvalidMonth(13))
+    );
+    assert_eq!(
+        CompactHijriDate::from_hijri(1501, 1, 1),
+        Err(DateError::OutOfRange)
+    );
+    assert_eq!(
+        CompactHijriDate::from_gr(2023, 2, 29),
+        Err(DateError::InvalidGregorianDate)
+    );
+    let extended = HijriDate::from_hijri_extended(1501, 1, 1).unwrap();
+    assert_eq!(
+        CompactHijriDate::try_from(&extended),
+        Err(DateError::OutOfRange)
+    );
+
+    let date = CompactHijriDate::from_hijri(1445, 9, 30).unwrap();
+    let next = date.checked_add_months(1).unwrap();
+    assert_eq!((1445, 10, 29), (next.year(), next.month(), next.day()));
+    assert_eq!(
+        Some(date),
+        next.checked_add_months(-1).unwrap().checked_add_days(1)
+    );
+    assert_eq!(None, CompactHijriDate::MAX.checked_add_months(1));
+    assert_eq!(None, CompactHijriDate::MIN.checked_sub_days(1));
+    assert_eq!(None, CompactHijriDate::MIN.checked_add_days(i64::MAX));
+    assert_eq!(None, CompactHijriDate::MAX.checked_sub_days(i64::MIN));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_interop() {
+    use std::convert::TryFrom;
+    use time::macros::date;
+
+    let hd = HijriDate::try_from(date!(2024 - 03 - 11)).unwrap();
+    assert_eq!("1445-9-1", hd.format("%Y-%m-%d"));
+    assert_eq!(Ok(date!(2024 - 03 - 11)), time::Date::try_from(&hd));
+    assert_eq!(
+        "1445-10-1",
+        (hd.clone() + time::Duration::days(30)).format("%Y-%m-%d")
+    );
+    assert_eq!(
+        "1445-8-29",
+        (hd - time::Duration::hours(47)).format("%Y-%m-%d")
+    );
+    assert!(HijriDate::try_from(date!(2077 - 11 - 17)).is_err());
+
+    let day_number = DayNumber::from(date!(1937 - 03 - 14));
+    assert_eq!(HijriDate::MIN.day_number(), day_number);
+    let datetime = date!(2024 - 03 - 11).with_hms(23, 0, 0).unwrap();
+    assert_eq!(day_number + 31_774, datetime.assume_utc().into());
+
+    let compact = CompactHijriDate::try_from(date!(2024 - 03 - 11)).unwrap();
+    assert_eq!(date!(2024 - 03 - 11), time::Date::from(compact));
+    let far = HijriDate::from_hijri_extended(9999, 1, 1).unwrap();
+    assert!(time::Date::try_from(&far).is_err());
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn jiff_interop() {
+    use jiff::civil::date;
+    use jiff::tz::{Offset, TimeZone};
+    use jiff::{Timestamp, ToSpan};
+    use std::convert::TryFrom;
+
+    let hd = HijriDate::try_from(date(2024, 3, 11)).unwrap();
+    assert_eq!("1445-9-1", hd.format("%Y-%m-%d"));
+    assert_eq!(date(2024, 3, 11), jiff::civil::Date::try_from(&hd).unwrap());
+    assert!(HijriDate::try_from(date(1937, 3, 13)).is_err());
+
+    // 2024-03-10 22:00 UTC is already the 11th in Mecca
+    let instant = Timestamp::from_second(1_710_108_000).unwrap();
+    let mecca = instant.to_zoned(TimeZone::fixed(Offset::constant(3)));
+    let utc = instant.to_zoned(TimeZone::UTC);
+    assert_eq!(Ok(hd.clone()), HijriDate::try_from(&mecca));
+    assert_eq!(
+        "1445-8-29",
+        HijriDate::try_from(&utc).unwrap().format("%Y-%m-%d")
+    );
+
+    let ymd = |d: HijriDate| d.format("%Y-%m-%d");
+    assert_eq!("1445-10-1", ymd(hd.clone() + 30.days()));
+    assert_eq!("1446-11-1", ymd(hd.clone() + 1.year().months(2)));
+    assert_eq!("1445-9-15", ymd(hd.clone() + 2.weeks()));
+    assert_eq!("1445-9-2", ymd(hd.clone() + 47.hours()));
+    let last = HijriDate::from_hijri(1445, 9, 30).unwrap();
+    assert_eq!("1445-10-29", ymd(last.clone() + 1.month()));
+    assert_eq!("1445-8-29", ymd(last - 1.month()));
+
+    let compact = CompactHijriDate::try_from(date(2024, 3, 11)).unwrap();
+    assert_eq!(date(2024, 3, 11), jiff::civil::Date::from(compact));
+    let far = HijriDate::from_hijri_extended(9999, 1, 1).unwrap();
+    assert!(jiff::civil::Date::try_from(&far).is_err());
+    assert!(jiff::civil::Date::try_from(DayNumber::from_jdn(i64::MAX)).is_err());
+}
+
+#[cfg(feature = "icu_calendar")]
+#[test]
+fn icu_calendar_interop() {
+    use icu_calendar::islamic::{IslamicCivil, IslamicUmmAlQura};
+    use icu_calendar::Date;
+    use std::convert::TryFrom;
+
+    let ymd = |year: i32, month: u32, day: u32| (year, month, day);
+    let hd = HijriDate::from_gr(2024, 3, 11).unwrap();
+    let uaq = Date::<IslamicUmmAlQura>::from(&hd);
+    assert_eq!(
+        ymd(1445, 9, 1),
+        ymd(uaq.year().number, uaq.month().ordinal, uaq.day_of_month().0)
+    );
+    assert_eq!(Ok(hd.clone()), HijriDate::try_from(&uaq));
+
+    let civil = Date::<IslamicCivil>::from(&hd);
+    assert_eq!(DayNumber::from(&civil), hd.day_number());
+    assert_eq!(Ok(hd.clone()), HijriDate::try_from(&civil));
+
+    let iso = Date::try_new_iso_date(1937, 3, 13).unwrap();
+    assert!(HijriDate::try_from(&iso).is_err());
+    assert_eq!(Err(DateError::OutOfRange), CompactHijriDate::try_from(&iso));
+
+    let compact = CompactHijriDate::from_hijri(1445, 9, 1).unwrap();
+    let uaq = Date::<IslamicUmmAlQura>::from(compact);
+    assert_eq!(Ok(compact), CompactHijriDate::try_from(&uaq));
+    let far = DayNumber::from_jdn(i64::MAX);
+    assert!(Date::<icu_calendar::Iso>::try_from(far).is_err());
+}
+
+// compares every day of the table with the Umm al-Qura calendar of ICU4X, which computes the
+// months astronomically, and pins the days that disagree
+#[cfg(feature = "icu_calendar")]
+#[test]
+fn icu_calendar_umalqura_conformance() {
+    use icu_calendar::islamic::IslamicUmmAlQura;
+    use icu_calendar::Date;
+
+    let mut disagreements: Vec<(String, usize)> = Vec::new();
+    let mut date = CompactHijriDate::MIN;
+    loop {
+        let icu = Date::<IslamicUmmAlQura>::from(date);
+        let icu = (
+            icu.year().number,
+            icu.month().ordinal as usize,
+            icu.day_of_month().0 as usize,
+        );
+        if icu != (date.year(), date.month(), date.day()) {
+            let month = format!("{}-{:02}", date.year(), date.month());
+            match disagreements.last_mut() {
+                Some((last, days)) if *last == month => *days += 1,
+                _ => disagreements.push((month, 1)),
+            }
+        }
+        date = match date.checked_add_days(1) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    let days: usize = disagreements.iter().map(|(_, days)| days).sum();
+    assert_eq!((399, 8878), (disagreements.len(), days));
+    assert_eq!(Some(&("1356-08".to_string(), 1)), disagreements.first());
+    assert_eq!(Some(&("1356-09".to_string(), 29)), disagreements.get(1));
+    assert_eq!(Some(&("1500-02".to_string(), 1)), disagreements.last());
+
+    // the months never start more than a day apart
+    for year in CompactHijriDate::MIN.year()..=CompactHijriDate::MAX.year() {
+        for month in 1..=12 {
+            let first = CompactHijriDate::from_hijri(year, month, 1).unwrap();
+            let icu = Date::try_new_ummalqura_date(year, month as u8, 1, IslamicUmmAlQura::new())
+                .unwrap();
+            let offset = DayNumber::from(&icu) - first.day_number();
+            assert!(offset.abs() <= 1, "{} starts {} days apart", first, offset);
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_types() {
+    use bytes::BytesMut;
+    use hijri_date::HijriText;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    let mut buf = BytesMut::new();
+    hd.to_sql_checked(&Type::DATE, &mut buf).unwrap();
+    // 2024-03-11 is 8836 days after 2000-01-01
+    assert_eq!(&buf[..], &8836i32.to_be_bytes()[..]);
+    assert_eq!(hd, HijriDate::from_sql(&Type::DATE, &buf).unwrap());
+    assert!(hd
+        .to_sql_checked(&Type::TEXT, &mut BytesMut::new())
+        .is_err());
+    // 1937-03-13 is out of the table, and infinity too
+    assert!(HijriDate::from_sql(&Type::DATE, &(-22939i32).to_be_bytes()).is_err());
+    assert!(HijriDate::from_sql(&Type::DATE, &i32::MAX.to_be_bytes()).is_err());
+
+    let text = HijriText(hd.clone());
+    let mut buf = BytesMut::new();
+    text.to_sql_checked(&Type::TEXT, &mut buf).unwrap();
+    assert_eq!(&buf[..], b"1445-09-01");
+    assert_eq!(text, HijriText::from_sql(&Type::VARCHAR, &buf).unwrap());
+    assert!(HijriText::from_sql(&Type::TEXT, b"1445-13-01").is_err());
+    let far = HijriText(HijriDate::from_hijri_extended(-4, 9, 1).unwrap());
+    assert_eq!(far.to_string(), "0005-09-01 BH");
+    assert_eq!(Ok(far.clone()), far.to_string().parse());
+}
+
+#[cfg(feature = "sqlx")]
+#[test]
+fn sqlx_postgres() {
+    use hijri_date::HijriText;
+    use sqlx::encode::IsNull;
+    use sqlx::postgres::types::Oid;
+    use sqlx::postgres::{PgArgumentBuffer, Postgres};
+    use sqlx::{Encode, Type};
+
+    let hd = HijriDate::from_hijri(1445, 9, 1).unwrap();
+    let mut buf = PgArgumentBuffer::default();
+    let is_null = Encode::<Postgres>::encode_by_ref(&hd, &mut buf).unwrap();
+    assert!(matches!(is_null, IsNull::No));
+    assert_eq!(&buf[..], &8836i32.to_be_bytes()[..]);
+    let date = <HijriDate as Type<Postgres>>::type_info();
+    assert_eq!(Some(Oid(1082)), date.oid());
+
+    let mut buf = PgArgumentBuffer::default();
+    let is_null = Encode::<Postgres>::encode_by_ref(&HijriText(hd), &mut buf).unwrap();
+    assert!(matches!(is_null, IsNull::No));
+    assert_eq!(&buf[..], b"1445-09-01");
+    assert_eq!(
+        "TEXT",
+        <HijriText as Type<Postgres>>::type_info().to_string()
+    );
+    assert!(<HijriText as Type<Postgres>>::compatible(&<&str as Type<
+        Postgres,
+    >>::type_info(
+    )));
+}